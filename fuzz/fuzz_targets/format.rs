@@ -0,0 +1,65 @@
+//! Feed arbitrary input through the parser and the formatter.
+//!
+//! The first two bytes pick the formatting options, and the rest is the config. Any panic is a bug,
+//! and so is output that doesn't format to itself or changes the meaning of the config.
+
+#![no_main]
+
+use hyprlang_fmt::config::{Config, IndentMode, ModsSeparator, SpacingContext};
+use hyprlang_fmt::cst;
+use hyprlang_fmt::parsed::format::{format_file, format_lines};
+use hyprlang_fmt::parsed::line::get_lines;
+use hyprlang_fmt::verify::{bug_report, verify};
+use libfuzzer_sys::fuzz_target;
+
+fn spacing_context(bits: u16) -> SpacingContext {
+    match bits % 3 {
+        0 => SpacingContext::Block,
+        1 => SpacingContext::Category,
+        _ => SpacingContext::File,
+    }
+}
+
+fn config(bits: u16) -> Config {
+    Config {
+        indent_width: (bits & 0b11) as u8,
+        indent_mode: if bits & 0b100 == 0 {
+            IndentMode::Spaces
+        } else {
+            IndentMode::Tabs
+        },
+        eq_spacing_context: spacing_context(bits >> 3 & 0b11),
+        comment_spacing_context: spacing_context(bits >> 5 & 0b11),
+        normalize_bind_mods: bits & 0b1000_0000 != 0,
+        bind_mods_separator: if bits & 0b1_0000_0000 == 0 {
+            ModsSeparator::Space
+        } else {
+            ModsSeparator::Underscore
+        },
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&options, data)) = data.split_first_chunk() else {
+        return;
+    };
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let config = config(u16::from_le_bytes(options));
+
+    // Invalid input is expected, but must be reported rather than panic
+    let Ok(tree) = cst::parse(input) else {
+        return;
+    };
+    let lines = get_lines(&tree);
+    let _ = format_lines(&lines, config);
+
+    let Ok(formatted) = format_file(input, config) else {
+        return;
+    };
+
+    if let Err(failure) = verify(input, &formatted, config) {
+        panic!("{}", bug_report(input, &formatted, &failure));
+    }
+});
@@ -12,9 +12,9 @@ in
     pname = "hyprlang-fmt";
     version = "1.0.1";
 
-    src = pkgs.lib.sourceByRegex ../. ["src" "src/cli" "src/components" "src/grammar" "src/parsed" ".+\.rs" ".+\.pest" "^Cargo.lock$" "^Cargo.toml$"];
+    src = pkgs.lib.sourceByRegex ../. ["^src(/[^/.]+)*$" "^src/.+\\.(rs|pest)$" "^Cargo.lock$" "^Cargo.toml$"];
 
-    cargoHash = "sha256-xO58pREgKYq6q7Nxnq+ib3F+5MWokPZ2SWnlq++fTsE=";
+    cargoHash = "sha256-ZCCQkFH/vIe5N3qcoCH6653YHpAOFbbHLWyBbNUU5zI=";
 
     meta = {
       mainProgram = "hyprlang-fmt";
@@ -0,0 +1,322 @@
+//! A typed syntax tree, built on the lossless [`crate::cst`].
+//!
+//! Blank lines and the spacing between tokens are dropped, but every node keeps where it came
+//! from, as both a byte range and 1-based lines and columns.
+
+use std::ops::Range;
+
+use crate::cst::{self, Element, Node};
+use crate::diagnostic::Position;
+use crate::grammar::Rule;
+use crate::parse::ParseError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub bytes: Range<usize>,
+    pub start: Position,
+    pub end: Position,
+}
+
+/// A piece of source text, like a name or a value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Text<'a> {
+    pub text: &'a str,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct File<'a> {
+    pub items: Vec<Item<'a>>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item<'a> {
+    Category(Category<'a>),
+    Assignment(Assignment<'a>),
+    Keyword(Keyword<'a>),
+    Comment(Comment<'a>),
+    Directive(Directive<'a>),
+}
+
+/// `name { ... }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Category<'a> {
+    pub name: Text<'a>,
+    pub items: Vec<Item<'a>>,
+    /// A comment after the closing brace.
+    pub comment: Option<Comment<'a>>,
+    pub span: Span,
+}
+
+/// A variable definition, `$name = value`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment<'a> {
+    /// The name, without its leading `$`.
+    pub name: Text<'a>,
+    pub value: Option<Text<'a>>,
+    pub comment: Option<Comment<'a>>,
+    pub span: Span,
+}
+
+/// An option or keyword, `key = value`, like `gaps_in = 5` or `bind = SUPER, Q, killactive`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyword<'a> {
+    pub key: Text<'a>,
+    pub value: Option<Text<'a>>,
+    pub comment: Option<Comment<'a>>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment<'a> {
+    /// The text after the leading `#`s and the space after them.
+    pub text: Text<'a>,
+    pub span: Span,
+}
+
+/// A line that changes how the rest of the config is read: `source = path`, or a
+/// `# hyprlang ...` comment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Directive<'a> {
+    /// `source` or `hyprlang`.
+    pub name: &'a str,
+    pub arguments: Text<'a>,
+    pub comment: Option<Comment<'a>>,
+    pub span: Span,
+}
+
+struct Builder<'a> {
+    text: &'a str,
+    /// The byte offset of the start of every line
+    line_starts: Vec<usize>,
+}
+
+impl<'a> Builder<'a> {
+    fn new(text: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+
+        Self { text, line_starts }
+    }
+
+    fn position(&self, offset: usize) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let start = self.line_starts[line - 1];
+
+        Position {
+            line,
+            column: self.text[start..offset].chars().count() + 1,
+        }
+    }
+
+    fn span(&self, bytes: Range<usize>) -> Span {
+        Span {
+            start: self.position(bytes.start),
+            end: self.position(bytes.end),
+            bytes,
+        }
+    }
+
+    fn text(&self, node: &Node) -> Text<'a> {
+        let text = &self.text[node.span.clone()];
+        // Trailing spaces are part of some rules
+        let end = node.span.start + text.trim_end().len();
+
+        Text {
+            text: &self.text[node.span.start..end],
+            span: self.span(node.span.start..end),
+        }
+    }
+
+    fn comment(&self, node: &Node) -> Comment<'a> {
+        let text = child(node, Rule::comment_text).map_or_else(
+            || Text {
+                text: "",
+                span: self.span(node.span.end..node.span.end),
+            },
+            |text| self.text(text),
+        );
+
+        Comment {
+            text,
+            span: self.span(node.span.clone()),
+        }
+    }
+
+    fn items(&self, node: &Node) -> Vec<Item<'a>> {
+        children(node)
+            .filter_map(|node| match node.rule {
+                Rule::category => Some(Item::Category(self.category(node))),
+                Rule::assignment => Some(Item::Assignment(self.assignment(node))),
+                Rule::bind => Some(self.keyword(node)),
+                Rule::comment => Some(self.comment_or_directive(node)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn category(&self, node: &Node) -> Category<'a> {
+        Category {
+            name: self.text(child(node, Rule::category_ident).expect("category has a name")),
+            items: child(node, Rule::category_inner)
+                .map(|inner| self.items(inner))
+                .unwrap_or_default(),
+            comment: child(node, Rule::comment).map(|comment| self.comment(comment)),
+            span: self.span(node.span.clone()),
+        }
+    }
+
+    fn assignment(&self, node: &Node) -> Assignment<'a> {
+        let ident = child(node, Rule::variable_ident).expect("assignment has a variable");
+        let mut name = self.text(ident);
+        name.text = &name.text[1..];
+        name.span = self.span(name.span.bytes.start + 1..name.span.bytes.end);
+
+        Assignment {
+            name,
+            value: child(node, Rule::variable_expression)
+                .map(|value| self.text(value))
+                .filter(|value| !value.text.is_empty()),
+            comment: child(node, Rule::comment).map(|comment| self.comment(comment)),
+            span: self.span(node.span.clone()),
+        }
+    }
+
+    fn keyword(&self, node: &Node) -> Item<'a> {
+        let key = self.text(child(node, Rule::bind_ident).expect("bind has a key"));
+        let value = child(node, Rule::bind_rhs)
+            .map(|value| self.text(value))
+            .filter(|value| !value.text.is_empty());
+        let comment = child(node, Rule::comment).map(|comment| self.comment(comment));
+        let span = self.span(node.span.clone());
+
+        match (key.text, value) {
+            ("source", Some(arguments)) => Item::Directive(Directive {
+                name: "source",
+                arguments,
+                comment,
+                span,
+            }),
+            (_, value) => Item::Keyword(Keyword {
+                key,
+                value,
+                comment,
+                span,
+            }),
+        }
+    }
+
+    fn comment_or_directive(&self, node: &Node) -> Item<'a> {
+        let comment = self.comment(node);
+
+        let Some(rest) = comment.text.text.strip_prefix("hyprlang ") else {
+            return Item::Comment(comment);
+        };
+        let start = comment.text.span.bytes.end - rest.len();
+
+        Item::Directive(Directive {
+            name: "hyprlang",
+            arguments: Text {
+                text: rest,
+                span: self.span(start..comment.text.span.bytes.end),
+            },
+            comment: None,
+            span: comment.span,
+        })
+    }
+}
+
+fn children<'b, 'a>(node: &'b Node<'a>) -> impl Iterator<Item = &'b Node<'a>> {
+    node.children.iter().filter_map(|child| match child {
+        Element::Node(node) => Some(node),
+        Element::Token(_) => None,
+    })
+}
+
+fn child<'b, 'a>(node: &'b Node<'a>, rule: Rule) -> Option<&'b Node<'a>> {
+    children(node).find(|child| child.rule == rule)
+}
+
+/// Parse `text` into a typed tree.
+pub fn parse(text: &str) -> Result<File<'_>, ParseError> {
+    let tree = cst::parse(text)?;
+    let builder = Builder::new(text);
+
+    Ok(File {
+        items: builder.items(&tree),
+        span: builder.span(tree.span.clone()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = [
+            "# hyprlang noerror true",
+            "source = ~/.config/hypr/colors.conf",
+            "$mod = SUPER # main",
+            "",
+            "general { # inline",
+            "    gaps_in = 5",
+            "    # inner",
+            "}",
+            "bind = $mod, Q, killactive",
+        ]
+        .join("\n");
+
+        let file = parse(&text).unwrap();
+
+        let [
+            Item::Directive(noerror),
+            Item::Directive(source),
+            Item::Assignment(variable),
+            Item::Category(category),
+            Item::Keyword(bind),
+        ] = file.items.as_slice()
+        else {
+            panic!("unexpected items: {:#?}", file.items);
+        };
+
+        assert_eq!(
+            (noerror.name, noerror.arguments.text),
+            ("hyprlang", "noerror true")
+        );
+        assert_eq!(source.arguments.text, "~/.config/hypr/colors.conf");
+
+        assert_eq!(variable.name.text, "mod");
+        assert_eq!(variable.name.span.start, Position { line: 3, column: 2 });
+        assert_eq!(variable.value.as_ref().unwrap().text, "SUPER");
+        assert_eq!(variable.comment.as_ref().unwrap().text.text, "main");
+
+        assert_eq!(category.name.text, "general");
+        assert_eq!(category.span.start, Position { line: 5, column: 1 });
+        assert_eq!(category.span.end, Position { line: 8, column: 2 });
+        let [
+            Item::Comment(trailing),
+            Item::Keyword(gaps),
+            Item::Comment(inner),
+        ] = category.items.as_slice()
+        else {
+            panic!("unexpected items: {:#?}", category.items);
+        };
+        assert_eq!(gaps.key.text, "gaps_in");
+        assert_eq!(trailing.text.text, "inline");
+        assert_eq!(
+            trailing.span.end,
+            Position {
+                line: 5,
+                column: 19
+            }
+        );
+        assert_eq!(&text[gaps.value.as_ref().unwrap().span.bytes.clone()], "5");
+        assert_eq!(inner.text.text, "inner");
+
+        assert_eq!(bind.key.text, "bind");
+        assert_eq!(bind.span.start, Position { line: 9, column: 1 });
+    }
+}
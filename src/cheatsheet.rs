@@ -0,0 +1,257 @@
+use serde_json::{Value, json};
+
+use crate::diagnostic::Location;
+use crate::parsed::bind::canonical_mods;
+use crate::parsed::line::Line;
+use crate::sources::Statement;
+use crate::variables::Variables;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub flags: String,
+    pub mods: Vec<String>,
+    pub key: String,
+    pub dispatcher: String,
+    pub args: Option<String>,
+    pub description: Option<String>,
+    pub location: Location,
+}
+
+impl Entry {
+    /// The key combination, e.g. `SUPER + SHIFT + Q`.
+    pub fn keys(&self) -> String {
+        self.mods
+            .iter()
+            .map(String::as_str)
+            .chain([self.key.as_str()])
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+
+    pub fn action(&self) -> String {
+        match &self.args {
+            Some(args) => format!("{} {args}", self.dispatcher),
+            None => self.dispatcher.clone(),
+        }
+    }
+}
+
+/// The binds of a single submap. `name` is `None` for binds outside of any submap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    pub name: Option<String>,
+    pub entries: Vec<Entry>,
+}
+
+impl Group {
+    pub fn title(&self) -> &str {
+        self.name.as_deref().unwrap_or("Global")
+    }
+}
+
+/// Collect every bind, with variables substituted, grouped by submap in order of first
+/// appearance.
+///
+/// The description of a bind is taken from, in order: the description field of a `bindd`,
+/// the comment on the line directly above it, or its trailing comment.
+pub fn collect(statements: &[Statement]) -> Vec<Group> {
+    let mut groups = vec![Group {
+        name: None,
+        entries: vec![],
+    }];
+    let mut current = 0;
+
+    let mut variables = Variables::default();
+    let mut previous: Option<&Line> = None;
+
+    for statement in statements {
+        let line = &statement.line;
+        let above = previous.replace(line);
+
+        let Some(info) = line.as_sectionable() else {
+            continue;
+        };
+        let rhs = info.rhs.unwrap_or_default();
+
+        if info.lhs.starts_with('$') {
+            variables.define(info.lhs, rhs);
+            continue;
+        }
+
+        if info.indent == 0 && info.lhs == "submap" {
+            let name = rhs.split(',').next().unwrap_or_default().trim();
+            let name = (!name.is_empty() && name != "reset").then(|| name.to_string());
+
+            current = groups
+                .iter()
+                .position(|group| group.name == name)
+                .unwrap_or_else(|| {
+                    groups.push(Group {
+                        name,
+                        entries: vec![],
+                    });
+
+                    groups.len() - 1
+                });
+            continue;
+        }
+
+        let Some(bind) = &info.bind else {
+            continue;
+        };
+
+        let comment_above = match above {
+            Some(Line::Comment(comment)) => comment.comment_text,
+            _ => None,
+        };
+
+        let description = bind
+            .description
+            .or(comment_above)
+            .or(info.comment_text)
+            .map(|text| variables.substitute(text.trim()))
+            .filter(|text| !text.is_empty());
+
+        groups[current].entries.push(Entry {
+            flags: bind.flags.to_string(),
+            mods: canonical_mods(&variables.substitute(bind.mods_str)),
+            key: bind.key.to_string(),
+            dispatcher: variables.substitute(bind.dispatcher),
+            args: bind.args.map(|args| variables.substitute(args)),
+            description,
+            location: statement
+                .source
+                .span(info.lhs, info.rhs.unwrap_or(info.lhs)),
+        });
+    }
+
+    groups.retain(|group| !group.entries.is_empty());
+
+    groups
+}
+
+pub fn to_markdown(groups: &[Group]) -> String {
+    let escape = |text: &str| text.replace('|', "\\|");
+
+    let mut sections = vec![];
+
+    for group in groups {
+        let mut lines = vec![
+            format!("## {}", group.title()),
+            String::new(),
+            "| Keys | Action | Description |".to_string(),
+            "| --- | --- | --- |".to_string(),
+        ];
+
+        for entry in &group.entries {
+            lines.push(format!(
+                "| {} | {} | {} |",
+                escape(&entry.keys()),
+                escape(&entry.action()),
+                escape(entry.description.as_deref().unwrap_or_default()),
+            ));
+        }
+
+        sections.push(lines.join("\n"));
+    }
+
+    sections.join("\n\n") + "\n"
+}
+
+pub fn to_html(groups: &[Group]) -> String {
+    let escape = |text: &str| {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    };
+
+    let mut lines = vec![];
+
+    for group in groups {
+        lines.push(format!("<h2>{}</h2>", escape(group.title())));
+        lines.push("<table>".to_string());
+        lines.push(
+            "  <thead><tr><th>Keys</th><th>Action</th><th>Description</th></tr></thead>"
+                .to_string(),
+        );
+        lines.push("  <tbody>".to_string());
+
+        for entry in &group.entries {
+            lines.push(format!(
+                "    <tr><td><kbd>{}</kbd></td><td><code>{}</code></td><td>{}</td></tr>",
+                escape(&entry.keys()),
+                escape(&entry.action()),
+                escape(entry.description.as_deref().unwrap_or_default()),
+            ));
+        }
+
+        lines.push("  </tbody>".to_string());
+        lines.push("</table>".to_string());
+    }
+
+    lines.join("\n") + "\n"
+}
+
+pub fn to_json(groups: &[Group]) -> Value {
+    groups
+        .iter()
+        .map(|group| {
+            json!({
+                "submap": group.name,
+                "binds": group.entries.iter().map(|entry| json!({
+                    "flags": entry.flags,
+                    "mods": entry.mods,
+                    "key": entry.key,
+                    "dispatcher": entry.dispatcher,
+                    "args": entry.args,
+                    "description": entry.description,
+                    "file": entry.location.name(),
+                    "line": entry.location.start.line,
+                })).collect::<Vec<_>>(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sources::{Source, Sources};
+
+    #[test]
+    fn test_collect() {
+        let sources = Sources::load(Source::new(
+            None,
+            [
+                "$terminal = kitty",
+                "# Open a terminal",
+                "bind = SUPER, Q, exec, $terminal",
+                "bind = SUPER, C, killactive, # Close",
+                "submap = resize",
+                "bind = , escape, submap, reset",
+                "submap = reset",
+                "bindd = SUPER SHIFT, E, Exit, exit,",
+                "",
+            ]
+            .join("\n"),
+        ));
+        let (statements, _) = sources.statements();
+
+        let groups = collect(&statements);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].name, None);
+        assert_eq!(groups[1].name.as_deref(), Some("resize"));
+
+        let global = &groups[0].entries;
+        assert_eq!(global.len(), 3);
+        assert_eq!(global[0].keys(), "SUPER + Q");
+        assert_eq!(global[0].action(), "exec kitty");
+        assert_eq!(global[0].description.as_deref(), Some("Open a terminal"));
+        assert_eq!(global[1].description.as_deref(), Some("Close"));
+        assert_eq!(global[2].keys(), "SUPER + SHIFT + E");
+        assert_eq!(global[2].description.as_deref(), Some("Exit"));
+    }
+}
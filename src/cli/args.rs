@@ -1,6 +1,9 @@
-use crate::config::{Config, IndentMode, SpacingContext};
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use hyprlang_fmt::config::{Config, IndentMode, ModsSeparator, SpacingContext};
+use hyprlang_fmt::schema::Dialect;
+use hyprlang_fmt::sources::Source;
 use std::io::Read;
+use std::path::{Path, PathBuf};
 
 #[allow(clippy::doc_markdown)]
 ///  _                      _                           __           _
@@ -14,8 +17,65 @@ use std::io::Read;
 ///
 /// A (the?) formatter for hyprlang.
 #[derive(Parser, Debug, Default)]
-#[command(version, verbatim_doc_comment)]
+#[command(version, verbatim_doc_comment, args_conflicts_with_subcommands = true)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub format: FormatArgs,
+
+    /// Print warnings about undefined, unused and redefined variables to stderr while formatting.
+    ///
+    /// Sourced files are resolved relative to the current directory.
+    #[arg(long, verbatim_doc_comment)]
+    pub warn_variables: bool,
+
+    /// Check the formatted output before printing it: that it formats to itself, and that it
+    /// resolves to the same settings as the input.
+    ///
+    /// Settings are resolved as in hyprland.conf, even for the configs of other programs, and sourced
+    /// files are not followed, as formatting doesn't change them.
+    ///
+    /// When either check fails, a bug report is printed to stderr and "--on-error" applies.
+    #[arg(long, verbatim_doc_comment)]
+    pub verify: bool,
+
+    /// What to print when the input can't be formatted, e.g. because it doesn't parse or fails "--verify".
+    ///
+    /// - "passthrough" prints the input unchanged, so editors formatting on save keep the buffer as it is.
+    /// - "recover" formats the lines that parse and prints the others unchanged, or the whole input
+    ///   unchanged when it can't be formatted for another reason.
+    /// - "fail" prints nothing.
+    ///
+    /// Either way, the problem is printed to stderr and the exit status is 1.
+    #[arg(long, verbatim_doc_comment, default_value_t)]
+    pub on_error: OnError,
+}
+
+#[derive(clap::ValueEnum, PartialEq, Clone, Copy, Debug, Default)]
+pub enum OnError {
+    #[default]
+    Passthrough,
+    Recover,
+    Fail,
+}
+
+impl std::fmt::Display for OnError {
+    fn fmt(
+        &self,
+        formatter: &mut std::fmt::Formatter<'_>,
+    ) -> std::result::Result<(), std::fmt::Error> {
+        formatter.write_str(match self {
+            Self::Passthrough => "passthrough",
+            Self::Recover => "recover",
+            Self::Fail => "fail",
+        })
+    }
+}
+
+#[derive(clap::Args, Debug, Default)]
+pub struct FormatArgs {
     /// How many chars to use for indentation
     ///
     /// If not specified, default is 2 (spaces)
@@ -41,10 +101,230 @@ pub struct Args {
     /// - in "file" context, trailing comments in the entire file will start at the same position.
     #[arg(long, verbatim_doc_comment, default_value_t)]
     pub comment_spacing_context: SpacingContext,
+    /// Rewrite the modifier field of every bind to a canonical order and upper case.
+    ///
+    /// Variables come first, then SUPER, CTRL, ALT, SHIFT, CAPS, MOD2, MOD3 and MOD5.
+    /// The key, dispatcher and arguments are left untouched.
+    #[arg(long, verbatim_doc_comment)]
+    pub normalize_bind_mods: bool,
+    /// Which separator to join modifiers with when "--normalize-bind-mods" is set.
+    /// Variables are always followed by a space, as "_" would be read as part of their name.
+    #[arg(long, verbatim_doc_comment, default_value_t)]
+    pub bind_mods_separator: ModsSeparator,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Report problems in a config and the files it sources, instead of formatting it.
+    ///
+    /// Option values are checked against a bundled schema of Hyprland options, and unknown
+    /// categories and options are reported with the closest known name.
+    /// Files that formatting would change are noted.
+    ///
+    /// Exits with status 2 when any errors are reported, 1 when only warnings are, and 0
+    /// otherwise.
+    #[command(verbatim_doc_comment)]
+    Lint(LintArgs),
+    /// Print a keybind cheat-sheet for a config and the files it sources.
+    ///
+    /// Binds are grouped by submap. The description of a bind is taken from the description field of a "bindd", the comment line directly above it, or its trailing comment.
+    #[command(verbatim_doc_comment)]
+    Binds(BindsArgs),
+    /// Run a language server over stdio.
+    ///
+    /// Supports formatting whole documents and ranges, and publishes parse errors as diagnostics.
+    /// Variables can be navigated to, searched for and renamed across sourced files.
+    /// Option names and values are completed from a bundled schema of Hyprland options, which also
+    /// provides hover documentation.
+    /// Categories, variables and banner comments make up the document outline, and categories
+    /// and comment blocks can be folded.
+    /// Indentation follows the options sent by the editor.
+    #[command(verbatim_doc_comment)]
+    Lsp(LspArgs),
+    /// Rewrite options that Hyprland has renamed, and report ones it has removed.
+    ///
+    /// The file is rewritten in place and formatted, keeping comments.
+    /// Reads stdin and prints to stdout when no file is given.
+    /// Exits with a non-zero status when anything could not be migrated automatically.
+    #[command(verbatim_doc_comment)]
+    Migrate(MigrateArgs),
+    /// Print the configuration Hyprland would end up with, as a flat list of settings.
+    ///
+    /// Sourced files are followed, variables substituted and "{{ }}" expressions evaluated.
+    /// Categories are flattened into full paths like "decoration:blur:size".
+    /// Options keep the last value they are set to, and keywords like "bind" list every value in order.
+    #[command(verbatim_doc_comment)]
+    Dump(DumpArgs),
+    /// Print the settings that differ between two configs, ignoring formatting.
+    ///
+    /// Both configs are resolved like "dump" does, so whitespace, comments, the order of options
+    /// and how categories are written make no difference.
+    /// The values of keywords like "bind" are compared regardless of their order, except for the
+    /// ones Hyprland applies in order, such as "exec-once" and "windowrule".
+    /// Exits with status 1 when the configs differ.
+    #[command(verbatim_doc_comment)]
+    Semdiff(SemdiffArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct SemdiffArgs {
+    /// The config to compare from.
+    pub old: PathBuf,
+
+    /// The config to compare to.
+    pub new: PathBuf,
+
+    /// Which program the configs are for.
+    /// Detected from the file name of the old config when not given, e.g. "hyprlock.conf" is a hyprlock config, and anything else a hyprland one.
+    #[arg(long, verbatim_doc_comment)]
+    pub dialect: Option<Dialect>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct DumpArgs {
+    /// The file to dump. Reads stdin when not given.
+    pub file: Option<PathBuf>,
+
+    /// Which program the config is for.
+    /// Detected from the file name when not given, e.g. "hyprlock.conf" is a hyprlock config, and anything else a hyprland one.
+    #[arg(long, verbatim_doc_comment)]
+    pub dialect: Option<Dialect>,
+
+    /// How to print the settings.
+    #[arg(long, verbatim_doc_comment, default_value_t)]
+    pub format: DumpFormat,
+}
+
+#[derive(clap::ValueEnum, PartialEq, Clone, Copy, Debug, Default)]
+pub enum DumpFormat {
+    #[default]
+    Json,
+    Toml,
+}
+
+impl std::fmt::Display for DumpFormat {
+    fn fmt(
+        &self,
+        formatter: &mut std::fmt::Formatter<'_>,
+    ) -> std::result::Result<(), std::fmt::Error> {
+        formatter.write_str(match self {
+            Self::Json => "json",
+            Self::Toml => "toml",
+        })
+    }
+}
+
+#[derive(clap::Args, Debug)]
+pub struct MigrateArgs {
+    /// The file to migrate. Reads stdin when not given.
+    pub file: Option<PathBuf>,
+
+    /// Which program the config is for. Only hyprland configs are changed.
+    /// Detected from the file name when not given, e.g. "hyprlock.conf" is a hyprlock config, and anything else a hyprland one.
+    #[arg(long, verbatim_doc_comment)]
+    pub dialect: Option<Dialect>,
+
+    /// Only apply changes from Hyprland releases after this version, e.g. "0.44.0".
+    #[arg(long, verbatim_doc_comment)]
+    pub since: Option<String>,
+
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct LspArgs {
+    /// Which program the configs are for.
+    /// Detected from each file name when not given, e.g. "hyprlock.conf" is a hyprlock config, and anything else a hyprland one.
+    #[arg(long, verbatim_doc_comment)]
+    pub dialect: Option<Dialect>,
+
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct LintArgs {
+    /// Files to lint. Reads stdin when none are given.
+    pub files: Vec<PathBuf>,
+
+    /// Which program the configs are for.
+    /// Detected from each file name when not given, e.g. "hyprlock.conf" is a hyprlock config, and anything else a hyprland one.
+    #[arg(long, verbatim_doc_comment)]
+    pub dialect: Option<Dialect>,
+
+    /// How to print what is found.
+    /// "json" prints one JSON object per line for each finding, followed by a summary object.
+    /// "sarif" prints a SARIF 2.1.0 log, and "checkstyle" a Checkstyle XML report.
+    #[arg(long, verbatim_doc_comment, default_value_t)]
+    pub message_format: MessageFormat,
+
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+#[derive(clap::ValueEnum, PartialEq, Clone, Copy, Debug, Default)]
+pub enum MessageFormat {
+    #[default]
+    Human,
+    Json,
+    Sarif,
+    Checkstyle,
+}
+
+impl std::fmt::Display for MessageFormat {
+    fn fmt(
+        &self,
+        formatter: &mut std::fmt::Formatter<'_>,
+    ) -> std::result::Result<(), std::fmt::Error> {
+        formatter.write_str(match self {
+            Self::Human => "human",
+            Self::Json => "json",
+            Self::Sarif => "sarif",
+            Self::Checkstyle => "checkstyle",
+        })
+    }
+}
+
+#[derive(clap::ValueEnum, PartialEq, Clone, Copy, Debug, Default)]
+pub enum BindsFormat {
+    #[default]
+    Markdown,
+    Html,
+    Json,
+}
+
+impl std::fmt::Display for BindsFormat {
+    fn fmt(
+        &self,
+        formatter: &mut std::fmt::Formatter<'_>,
+    ) -> std::result::Result<(), std::fmt::Error> {
+        formatter.write_str(match self {
+            Self::Markdown => "markdown",
+            Self::Html => "html",
+            Self::Json => "json",
+        })
+    }
+}
+
+#[derive(clap::Args, Debug)]
+pub struct BindsArgs {
+    /// The file to read binds from. Reads stdin when not given.
+    pub file: Option<PathBuf>,
+
+    /// What to print the cheat-sheet as.
+    #[arg(long, verbatim_doc_comment, default_value_t)]
+    pub format: BindsFormat,
 }
 
 impl From<Args> for Config {
     fn from(args: Args) -> Self {
+        args.format.into()
+    }
+}
+
+impl From<FormatArgs> for Config {
+    fn from(args: FormatArgs) -> Self {
         let indent_width = args.indent_width.unwrap_or({
             match args.indent_mode {
                 IndentMode::Tabs => 1,
@@ -55,20 +335,32 @@ impl From<Args> for Config {
         let indent_mode = args.indent_mode;
         let eq_spacing_context = args.eq_spacing_context;
         let comment_spacing_context = args.comment_spacing_context;
+        let normalize_bind_mods = args.normalize_bind_mods;
+        let bind_mods_separator = args.bind_mods_separator;
 
         Self {
             indent_width,
             indent_mode,
             eq_spacing_context,
             comment_spacing_context,
+            normalize_bind_mods,
+            bind_mods_separator,
         }
     }
 }
 
-pub fn get_config() -> Config {
-    let args = Args::parse();
+pub fn get_args() -> Args {
+    Args::parse()
+}
 
-    args.into()
+/// Read `path`, or stdin when `path` is `None`. Reports why on stderr when `path` can't be read.
+pub fn get_source(path: Option<&Path>) -> Option<Source> {
+    match path {
+        Some(path) => Source::read(path)
+            .inspect_err(|e| eprintln!("error: unable to read {}: {e}", path.display()))
+            .ok(),
+        None => Some(Source::new(None, get_file())),
+    }
 }
 
 pub fn get_file() -> String {
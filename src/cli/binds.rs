@@ -0,0 +1,33 @@
+use std::process::ExitCode;
+
+use hyprlang_fmt::cheatsheet;
+use hyprlang_fmt::sources::Sources;
+
+use crate::cli::args::{BindsArgs, BindsFormat};
+use crate::cli::get_source;
+
+pub fn binds(args: &BindsArgs) -> ExitCode {
+    let Some(root) = get_source(args.file.as_deref()) else {
+        return ExitCode::FAILURE;
+    };
+    let sources = Sources::load(root);
+    let (statements, diagnostics) = sources.statements();
+
+    for diagnostic in diagnostics.iter().chain(&sources.diagnostics) {
+        eprintln!("{diagnostic}");
+    }
+
+    let groups = cheatsheet::collect(&statements);
+
+    match args.format {
+        BindsFormat::Markdown => print!("{}", cheatsheet::to_markdown(&groups)),
+        BindsFormat::Html => print!("{}", cheatsheet::to_html(&groups)),
+        BindsFormat::Json => println!("{:#}", cheatsheet::to_json(&groups)),
+    }
+
+    if diagnostics.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
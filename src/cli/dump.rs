@@ -0,0 +1,38 @@
+use std::process::ExitCode;
+
+use hyprlang_fmt::dump;
+use hyprlang_fmt::schema::Dialect;
+use hyprlang_fmt::sources::Sources;
+
+use crate::cli::args::{DumpArgs, DumpFormat};
+use crate::cli::get_source;
+
+pub fn dump(args: &DumpArgs) -> ExitCode {
+    let Some(root) = get_source(args.file.as_deref()) else {
+        return ExitCode::FAILURE;
+    };
+    let dialect = args
+        .dialect
+        .or_else(|| root.path.as_deref().and_then(Dialect::detect))
+        .unwrap_or_default();
+
+    let sources = Sources::load(root);
+    let (statements, diagnostics) = sources.statements();
+
+    for diagnostic in diagnostics.iter().chain(&sources.diagnostics) {
+        eprintln!("{diagnostic}");
+    }
+
+    let settings = dump::settings(dialect.schema(), &statements);
+
+    match args.format {
+        DumpFormat::Json => println!("{:#}", dump::to_json(&settings)),
+        DumpFormat::Toml => print!("{}", dump::to_toml(&settings)),
+    }
+
+    if diagnostics.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
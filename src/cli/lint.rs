@@ -0,0 +1,76 @@
+use std::process::ExitCode;
+
+use hyprlang_fmt::config::Config;
+use hyprlang_fmt::diagnostic::Severity;
+use hyprlang_fmt::lint;
+use hyprlang_fmt::lint::format::would_reformat;
+use hyprlang_fmt::report;
+use hyprlang_fmt::schema::Dialect;
+use hyprlang_fmt::sources::Sources;
+
+use crate::cli::args::{LintArgs, MessageFormat};
+use crate::cli::get_source;
+
+pub fn lint(args: LintArgs) -> ExitCode {
+    let roots = if args.files.is_empty() {
+        get_source(None).map(|root| vec![root])
+    } else {
+        args.files
+            .iter()
+            .map(|path| get_source(Some(path)))
+            .collect::<Option<Vec<_>>>()
+    };
+    let Some(roots) = roots else {
+        return ExitCode::from(2);
+    };
+    let config = Config::from(args.format);
+
+    let mut diagnostics = vec![];
+    let mut files = 0;
+
+    for root in roots {
+        let dialect = args
+            .dialect
+            .or_else(|| root.path.as_deref().and_then(Dialect::detect))
+            .unwrap_or_default();
+        let sources = Sources::load(root);
+
+        diagnostics.extend(lint::lint(&sources, dialect));
+        diagnostics.extend(
+            sources
+                .files
+                .iter()
+                .filter_map(|source| would_reformat(source, config)),
+        );
+        files += sources.files.len();
+    }
+
+    diagnostics.sort_by(|a, b| a.location.cmp(&b.location));
+
+    match args.message_format {
+        MessageFormat::Human => {
+            for diagnostic in &diagnostics {
+                println!("{diagnostic}");
+            }
+        }
+        MessageFormat::Json => {
+            for diagnostic in &diagnostics {
+                println!("{}", report::to_json(diagnostic));
+            }
+
+            println!("{}", report::summary(&diagnostics, files));
+        }
+        MessageFormat::Sarif => println!("{:#}", report::sarif(&diagnostics)),
+        MessageFormat::Checkstyle => print!("{}", report::checkstyle(&diagnostics)),
+    }
+
+    match diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.severity)
+        .max()
+    {
+        Some(Severity::Error) => ExitCode::from(2),
+        Some(Severity::Warning) => ExitCode::FAILURE,
+        Some(Severity::Note) | None => ExitCode::SUCCESS,
+    }
+}
@@ -0,0 +1,25 @@
+use std::io;
+use std::process::ExitCode;
+
+use hyprlang_fmt::lsp;
+
+use crate::cli::args::LspArgs;
+
+pub fn lsp(args: LspArgs) -> ExitCode {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    match lsp::run(
+        &mut stdin.lock(),
+        &mut stdout.lock(),
+        args.format.into(),
+        args.dialect,
+    ) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("hyprlang-fmt lsp: {e}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
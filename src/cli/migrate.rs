@@ -0,0 +1,59 @@
+use std::process::ExitCode;
+
+use hyprlang_fmt::diagnostic::Severity;
+use hyprlang_fmt::migrate;
+use hyprlang_fmt::parsed::format::format_file;
+use hyprlang_fmt::schema::Dialect;
+
+use crate::cli::args::MigrateArgs;
+use crate::cli::get_source;
+
+pub fn migrate(args: MigrateArgs) -> ExitCode {
+    let Some(source) = get_source(args.file.as_deref()) else {
+        return ExitCode::FAILURE;
+    };
+
+    let dialect = args
+        .dialect
+        .or_else(|| source.path.as_deref().and_then(Dialect::detect))
+        .unwrap_or_default();
+
+    let (migrated, diagnostics) = match migrate::migrate(&source, dialect, args.since.as_deref()) {
+        Ok(migrated) => migrated,
+        Err(e) => {
+            eprintln!("{}", source.parse_error(&e));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for diagnostic in &diagnostics {
+        eprintln!("{diagnostic}");
+    }
+
+    let formatted = match format_file(&migrated, args.format.into()) {
+        Ok(formatted) => formatted,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match &args.file {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, formatted) {
+                eprintln!("error: unable to write {}: {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{formatted}"),
+    }
+
+    if diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.severity > Severity::Note)
+    {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -1,5 +1,17 @@
 pub mod args;
+mod binds;
+mod dump;
+mod lint;
+mod lsp;
+mod migrate;
 mod run;
+mod semdiff;
 
-pub use args::{get_config, get_file};
-pub use run::run;
+pub use args::{get_args, get_file, get_source};
+pub use binds::binds;
+pub use dump::dump;
+pub use lint::lint;
+pub use lsp::lsp;
+pub use migrate::migrate;
+pub use run::{run, warn_variables};
+pub use semdiff::semdiff;
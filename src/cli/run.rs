@@ -1,20 +1,87 @@
-use crate::config::Config;
-use crate::parse::get_file_tokens_iterator;
-use crate::parsed::format::format_lines;
-use crate::parsed::line::get_lines;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::ExitCode;
+
+use hyprlang_fmt::config::Config;
+use hyprlang_fmt::lint::variables::variables;
+use hyprlang_fmt::parsed::format::{FormatError, format_file, format_file_recovering};
+use hyprlang_fmt::sources::{Source, Sources};
+use hyprlang_fmt::verify::{bug_report, verify};
+
+use crate::cli::args::OnError;
+
+/// Format `file` to stdout, checking the output first when `check` is set. When it can't be
+/// formatted, the problem is printed to stderr and `on_error` decides what is printed to stdout.
+pub fn run(config: Config, check: bool, on_error: OnError, file: &str) -> ExitCode {
+    // The panic message is printed by the panic hook
+    let Ok(result) = panic::catch_unwind(AssertUnwindSafe(|| {
+        let formatted = parse(config, file)?;
+        let checked = check.then(|| verify(file, &formatted, config)).transpose();
+
+        Ok::<_, FormatError>((formatted, checked))
+    })) else {
+        return fail(on_error, file);
+    };
+
+    match result {
+        Ok((formatted, Err(failure))) => {
+            eprint!("{}", bug_report(file, &formatted, &failure));
+
+            fail(on_error, file)
+        }
+        Ok((formatted, Ok(_))) => {
+            print!("{formatted}");
+
+            ExitCode::SUCCESS
+        }
+        Err(FormatError::Parse(_)) => {
+            for diagnostic in Source::new(None, file.to_string()).parse_errors() {
+                eprintln!("{diagnostic}");
+            }
+
+            if on_error == OnError::Recover {
+                // The lines that parse are formatted, and the problem is still reported
+                if let Ok(Ok((formatted, _))) =
+                    panic::catch_unwind(|| format_file_recovering(file, config))
+                {
+                    print!("{formatted}");
+
+                    return ExitCode::FAILURE;
+                }
+            }
+
+            fail(on_error, file)
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+
+            fail(on_error, file)
+        }
+    }
+}
 
-pub fn run(config: Config, file: &str) {
-    let parsed = parse(config, file);
+fn fail(on_error: OnError, file: &str) -> ExitCode {
+    if on_error != OnError::Fail {
+        print!("{file}");
+    }
 
-    print!("{parsed}");
+    ExitCode::FAILURE
 }
 
-fn parse(config: Config, file: &str) -> String {
-    let pairs = get_file_tokens_iterator(file).expect("error getting file tokens iterator");
+/// Print variable diagnostics to stderr, without affecting the formatted output.
+pub fn warn_variables(file: &str) {
+    let sources = Sources::load(Source::new(None, file.to_string()));
+    let (statements, _) = sources.statements();
 
-    let lines = get_lines(pairs);
+    let mut diagnostics = variables(&statements);
+    diagnostics.sort_by(|a, b| a.location.cmp(&b.location));
 
-    format_lines(&lines, config).expect("error formatting lines")
+    for diagnostic in diagnostics {
+        eprintln!("{diagnostic}");
+    }
+}
+
+fn parse(config: Config, file: &str) -> Result<String, FormatError> {
+    format_file(file, config)
 }
 
 #[cfg(test)]
@@ -23,7 +90,7 @@ mod tests {
 
     use super::*;
     use crate::cli::args::Args;
-    use crate::config::SpacingContext;
+    use hyprlang_fmt::config::SpacingContext;
 
     fn concat<'a, I>(parts: I) -> String
     where
@@ -121,6 +188,12 @@ mod tests {
             expected_inc.push_str("\n");
             actual_inc.push_str("\n");
         }
+
+        // One of them has lines past the end of the other
+        assert_eq!(
+            expected.split_terminator('\n').collect::<Vec<_>>(),
+            actual.split_terminator('\n').collect::<Vec<_>>()
+        );
     }
 
     static FILE: LazyLock<String> = LazyLock::new(|| {
@@ -240,7 +313,7 @@ mod tests {
             "foo = bar",
         ]);
 
-        let actual = parse(config, &FILE);
+        let actual = parse(config, &FILE).unwrap();
 
         assert_fmt(&expected, &actual);
     }
@@ -306,7 +379,7 @@ mod tests {
             "foo = bar",
         ]);
 
-        let actual = parse(config, &FILE);
+        let actual = parse(config, &FILE).unwrap();
 
         assert_fmt(&expected, &actual);
     }
@@ -372,7 +445,7 @@ mod tests {
             "foo = bar",
         ]);
 
-        let actual = parse(config, &FILE);
+        let actual = parse(config, &FILE).unwrap();
 
         assert_fmt(&expected, &actual);
     }
@@ -438,7 +511,7 @@ mod tests {
             "foo = bar",
         ]);
 
-        let actual = parse(config, &FILE);
+        let actual = parse(config, &FILE).unwrap();
 
         assert_fmt(&expected, &actual);
     }
@@ -504,7 +577,7 @@ mod tests {
             "foo = bar",
         ]);
 
-        let actual = parse(config, &FILE);
+        let actual = parse(config, &FILE).unwrap();
 
         assert_fmt(&expected, &actual);
     }
@@ -570,7 +643,63 @@ mod tests {
             "foo              = bar",
         ]);
 
-        let actual = parse(config, &FILE);
+        let actual = parse(config, &FILE).unwrap();
+
+        assert_fmt(&expected, &actual);
+    }
+
+    #[test]
+    fn test_normalize_bind_mods() {
+        let args = Args::default();
+        let mut config = Config::from(args);
+
+        config.normalize_bind_mods = true;
+
+        let file = concat([
+            "bind = shift $mainMod, Q, exec, kitty # terminal",
+            "bindl = ctrl_super, L, exec, hyprlock",
+            "bind = , XF86AudioMute, exec, wpctl",
+            "monitor = shift super, preferred",
+            "",
+        ]);
+
+        let expected = concat([
+            "bind    = $mainMod SHIFT, Q, exec, kitty # terminal",
+            "bindl   = SUPER CTRL, L, exec, hyprlock",
+            "bind    = , XF86AudioMute, exec, wpctl",
+            "monitor = shift super, preferred",
+        ]);
+
+        let actual = parse(config, &file).unwrap();
+
+        assert_fmt(&expected, &actual);
+    }
+
+    #[test]
+    fn test_expressions() {
+        let args = Args::default();
+        let config = Config::from(args);
+
+        let file = concat([
+            "$gap = {{ 1+2 }}",
+            "general {",
+            "gaps_out = {{$gap*-(2+ 1)}}",
+            "}",
+            "bind = , Q, exec, foo {{ (a)*2 }} \\{{ x }}",
+            "",
+        ]);
+
+        let expected = concat([
+            "$gap = {{ 1 + 2 }}",
+            "",
+            "general {",
+            "  gaps_out = {{$gap * -(2 + 1)}}",
+            "}",
+            "",
+            "bind = , Q, exec, foo {{ (a) * 2 }} \\{{ x }}",
+        ]);
+
+        let actual = parse(config, &file).unwrap();
 
         assert_fmt(&expected, &actual);
     }
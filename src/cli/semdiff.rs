@@ -0,0 +1,49 @@
+use std::process::ExitCode;
+
+use hyprlang_fmt::dump::{Settings, settings};
+use hyprlang_fmt::schema::Dialect;
+use hyprlang_fmt::semdiff;
+use hyprlang_fmt::sources::Sources;
+
+use crate::cli::args::SemdiffArgs;
+use crate::cli::get_source;
+
+fn resolve(sources: &Sources, dialect: Dialect) -> Option<Settings> {
+    let (statements, diagnostics) = sources.statements();
+
+    for diagnostic in diagnostics.iter().chain(&sources.diagnostics) {
+        eprintln!("{diagnostic}");
+    }
+
+    diagnostics
+        .is_empty()
+        .then(|| settings(dialect.schema(), &statements))
+}
+
+pub fn semdiff(args: &SemdiffArgs) -> ExitCode {
+    let dialect = args
+        .dialect
+        .or_else(|| Dialect::detect(&args.old))
+        .unwrap_or_default();
+
+    let (Some(old), Some(new)) = (get_source(Some(&args.old)), get_source(Some(&args.new))) else {
+        return ExitCode::from(2);
+    };
+    let (old, new) = (Sources::load(old), Sources::load(new));
+
+    let (Some(old), Some(new)) = (resolve(&old, dialect), resolve(&new, dialect)) else {
+        return ExitCode::from(2);
+    };
+
+    let changes = semdiff::semdiff(&old, &new);
+
+    for change in &changes {
+        println!("{change}");
+    }
+
+    if changes.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -47,10 +47,40 @@ impl std::fmt::Display for SpacingContext {
     }
 }
 
+#[derive(clap::ValueEnum, PartialEq, Clone, Copy, Debug, Default)]
+pub enum ModsSeparator {
+    #[default]
+    Space,
+    Underscore,
+}
+
+impl ModsSeparator {
+    pub fn into_str(self) -> &'static str {
+        match self {
+            ModsSeparator::Space => " ",
+            ModsSeparator::Underscore => "_",
+        }
+    }
+}
+
+impl std::fmt::Display for ModsSeparator {
+    fn fmt(
+        &self,
+        formatter: &mut std::fmt::Formatter<'_>,
+    ) -> std::result::Result<(), std::fmt::Error> {
+        formatter.write_str(match self {
+            Self::Space => "space",
+            Self::Underscore => "underscore",
+        })
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, Default)]
 pub struct Config {
     pub indent_width: u8,
     pub indent_mode: IndentMode,
     pub eq_spacing_context: SpacingContext,
     pub comment_spacing_context: SpacingContext,
+    pub normalize_bind_mods: bool,
+    pub bind_mods_separator: ModsSeparator,
 }
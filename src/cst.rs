@@ -0,0 +1,276 @@
+//! A lossless concrete syntax tree.
+//!
+//! Unlike [`crate::parsed::line::Line`]s, every byte of the input belongs to the tree: each
+//! grammar rule is a node, and the text matched by silent grammar rules and the whitespace between
+//! rules are kept as tokens of their own, so printing a tree gives back exactly the text it was
+//! parsed from.
+
+use std::fmt;
+use std::ops::Range;
+
+use pest::Parser;
+use pest::iterators::Pair;
+
+use crate::grammar::{HyprlangParser, Rule};
+use crate::parse::{ParseError, recover};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// The text of a grammar rule without inner rules, like `comment_text` or `newline`.
+    Text,
+    /// Spaces and tabs between other tokens.
+    Whitespace,
+    /// Any other text, like `=`, `{` or `$`, matched by silent grammar rules.
+    Punctuation,
+    /// A line that doesn't parse, from [`parse_recovering`].
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    /// The byte range of the token in the parsed text.
+    pub span: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Element<'a> {
+    Node(Node<'a>),
+    Token(Token<'a>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node<'a> {
+    pub rule: Rule,
+    /// The text of the node, trivia included.
+    pub text: &'a str,
+    /// The byte range of the node in the parsed text.
+    pub span: Range<usize>,
+    pub children: Vec<Element<'a>>,
+}
+
+/// Split `text` (starting at byte `offset`) into whitespace and punctuation tokens.
+fn trivia<'a>(text: &'a str, offset: usize, elements: &mut Vec<Element<'a>>) {
+    let mut rest = text;
+    let mut start = offset;
+
+    while !rest.is_empty() {
+        let is_whitespace = rest.starts_with([' ', '\t']);
+        let len = rest
+            .find(|c: char| matches!(c, ' ' | '\t') != is_whitespace)
+            .unwrap_or(rest.len());
+
+        elements.push(Element::Token(Token {
+            kind: if is_whitespace {
+                TokenKind::Whitespace
+            } else {
+                TokenKind::Punctuation
+            },
+            text: &rest[..len],
+            span: start..start + len,
+        }));
+
+        rest = &rest[len..];
+        start += len;
+    }
+}
+
+/// Build the node of `pair`, slicing its tokens out of `text`. Comments spanning one of the
+/// `invalid` lines are taken as error tokens.
+fn node<'a>(text: &'a str, pair: Pair<'_, Rule>, invalid: &[Range<usize>]) -> Node<'a> {
+    let span = pair.as_span().start()..pair.as_span().end();
+    let rule = pair.as_rule();
+
+    let mut inner = pair.into_inner().peekable();
+    if inner.peek().is_none() {
+        return Node {
+            rule,
+            text: &text[span.clone()],
+            span: span.clone(),
+            children: vec![Element::Token(Token {
+                kind: TokenKind::Text,
+                text: &text[span.clone()],
+                span,
+            })],
+        };
+    }
+
+    let mut children = vec![];
+    let mut last = span.start;
+
+    for pair in inner {
+        let start = pair.as_span().start();
+        trivia(&text[last..start], last, &mut children);
+
+        last = pair.as_span().end();
+        // Skip zero-width rules like `EOI`
+        if last > start {
+            let span = start..last;
+
+            children.push(
+                if pair.as_rule() == Rule::comment && invalid.contains(&span) {
+                    Element::Token(Token {
+                        kind: TokenKind::Error,
+                        text: &text[span.clone()],
+                        span,
+                    })
+                } else {
+                    Element::Node(node(text, pair, invalid))
+                },
+            );
+        }
+    }
+
+    trivia(&text[last..span.end], last, &mut children);
+
+    Node {
+        rule,
+        text: &text[span.clone()],
+        span,
+        children,
+    }
+}
+
+/// Parse `text` into a tree rooted at a `file` node.
+pub fn parse(text: &str) -> Result<Node<'_>, ParseError> {
+    let pair = HyprlangParser::parse(Rule::file, text)
+        .map_err(|e| ParseError::InvalidInput(Box::new(e)))?
+        .next()
+        .expect("the file rule always matches once");
+
+    Ok(node(text, pair, &[]))
+}
+
+/// Parse `text` even if some of its lines don't parse: they are kept in the tree as error tokens,
+/// and returned along with why they don't parse.
+pub fn parse_recovering(text: &str) -> Result<(Node<'_>, Vec<ParseError>), ParseError> {
+    let recovered = recover(text)?;
+    let pair = HyprlangParser::parse(Rule::file, &recovered.text)
+        .map_err(|e| ParseError::InvalidInput(Box::new(e)))?
+        .next()
+        .expect("the file rule always matches once");
+
+    let (invalid, errors): (Vec<_>, Vec<_>) = recovered.invalid.into_iter().unzip();
+
+    Ok((node(text, pair, &invalid), errors))
+}
+
+impl<'a> Node<'a> {
+    /// Every token of the tree, in order.
+    pub fn tokens(&self) -> Vec<&Token<'a>> {
+        let mut tokens = vec![];
+
+        for child in &self.children {
+            match child {
+                Element::Node(node) => tokens.extend(node.tokens()),
+                Element::Token(token) => tokens.push(token),
+            }
+        }
+
+        tokens
+    }
+
+    /// Every node of the tree (including this one) matched by `rule`, outermost first.
+    pub fn descendants(&self, rule: Rule) -> Vec<&Node<'a>> {
+        let mut found = vec![];
+
+        if self.rule == rule {
+            found.push(self);
+        }
+
+        for child in &self.children {
+            if let Element::Node(node) = child {
+                found.extend(node.descendants(rule));
+            }
+        }
+
+        found
+    }
+
+    /// The tree printed with the text of some nodes replaced: `replace` is called for every node,
+    /// outermost first, and a node it returns text for is printed as that text instead of its
+    /// children.
+    pub fn rewrite(&self, replace: &mut impl FnMut(&Node<'a>) -> Option<String>) -> String {
+        if let Some(text) = replace(self) {
+            return text;
+        }
+
+        self.children
+            .iter()
+            .map(|child| match child {
+                Element::Node(node) => node.rewrite(replace),
+                Element::Token(token) => token.text.to_string(),
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Node<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for token in self.tokens() {
+            f.write_str(token.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lossless() {
+        let text = [
+            "#### banner ####",
+            "$mod =   SUPER # trailing",
+            "",
+            "general {  ",
+            "    col.active_border = rgba(33ccffee)  ##not a comment",
+            "    gaps_out = {{ $gap*2 }}",
+            "}",
+            "bind = $mod,   Q, exec,kitty",
+        ]
+        .join("\n");
+
+        let tree = parse(&text).unwrap();
+        assert_eq!(tree.to_string(), text);
+
+        let tokens = tree.tokens();
+        let mut last = 0;
+        for token in &tokens {
+            assert_eq!(token.span.start, last);
+            last = token.span.end;
+        }
+        assert_eq!(last, text.len());
+
+        assert_eq!(tree.descendants(Rule::category).len(), 1);
+        assert_eq!(
+            tree.rewrite(
+                &mut |node| (node.rule == Rule::category_ident).then(|| "decoration".to_string())
+            )
+            .lines()
+            .nth(3),
+            Some("decoration {  ")
+        );
+    }
+
+    #[test]
+    fn test_recovering() {
+        let text = "$a = 1\ngeneral {\n  gaps_in = 5\n  =oops\n}\nbind = ]\ndecoration {\n";
+
+        let (tree, errors) = parse_recovering(text).unwrap();
+        assert_eq!(tree.to_string(), text);
+        assert_eq!(errors.len(), 3);
+
+        let invalid = tree
+            .tokens()
+            .into_iter()
+            .filter(|token| token.kind == TokenKind::Error)
+            .map(|token| token.text)
+            .collect::<Vec<_>>();
+        assert_eq!(invalid, ["  =oops", "bind = ]", "decoration {"]);
+        assert_eq!(tree.descendants(Rule::category).len(), 1);
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use crate::expression::expand;
+use crate::lint::with_categories;
+use crate::parsed::line::Line;
+use crate::schema::{Schema, Type, path};
+use crate::sources::{Statement, source_value};
+use crate::variables::Variables;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Setting {
+    /// An option, which takes the last value it is set to.
+    Value(String),
+    /// A keyword like `bind` or `exec-once`, with every value it is given in order.
+    List(Vec<String>),
+}
+
+/// The configuration Hyprland ends up with, keyed by full path like `decoration:blur:size`.
+/// Variables are included with their leading `$`.
+pub type Settings = BTreeMap<String, Setting>;
+
+/// `rhs` as Hyprland sees it: escaped `##` unescaped, variables substituted and expressions
+/// evaluated. Expressions that can't be evaluated are kept as written.
+fn resolve(rhs: &str, variables: &Variables) -> String {
+    let value = variables.substitute(rhs.trim()).replace("##", "#");
+
+    expand(&value, variables).unwrap_or(value)
+}
+
+/// Resolve `statements` into the settings they make, following `schema` to tell options from
+/// keywords.
+///
+/// Binds and keywords are collected into lists. Keys unknown to `schema` are treated as options
+/// until they are set more than once.
+pub fn settings(schema: &Schema, statements: &[Statement]) -> Settings {
+    let mut settings = Settings::new();
+    let mut variables = Variables::default();
+
+    for (categories, statement) in with_categories(statements) {
+        let Line::Sectioned(info) = &statement.line else {
+            continue;
+        };
+        let Some(rhs) = info.rhs else {
+            continue;
+        };
+
+        if source_value(&statement.line).is_some() {
+            continue;
+        }
+
+        if info.lhs.starts_with('$') {
+            let value = resolve(rhs, &variables);
+
+            variables.define(info.lhs, &value);
+            settings.insert(info.lhs.to_string(), Setting::Value(value));
+            continue;
+        }
+
+        let mut key = categories.join(":");
+        if !key.is_empty() {
+            key.push(':');
+        }
+        key.push_str(info.lhs);
+
+        let value = resolve(rhs, &variables);
+        let spec = schema.get(&path(&categories, info.lhs));
+        let is_keyword = info.bind.is_some() || spec.is_some_and(|spec| spec.ty == Type::Keyword);
+
+        match settings.get_mut(&key) {
+            Some(Setting::List(values)) => values.push(value),
+            Some(Setting::Value(previous)) if spec.is_none() => {
+                let previous = std::mem::take(previous);
+                settings.insert(key, Setting::List(vec![previous, value]));
+            }
+            _ if is_keyword => {
+                settings.insert(key, Setting::List(vec![value]));
+            }
+            _ => {
+                settings.insert(key, Setting::Value(value));
+            }
+        }
+    }
+
+    settings
+}
+
+pub fn to_json(settings: &Settings) -> Value {
+    settings
+        .iter()
+        .map(|(key, setting)| {
+            let value = match setting {
+                Setting::Value(value) => Value::from(value.as_str()),
+                Setting::List(values) => Value::from(values.clone()),
+            };
+
+            (key.clone(), value)
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+/// REF
+/// - <https://toml.io/en/v1.0.0#string>
+fn toml_string(text: &str) -> String {
+    let mut quoted = String::from('"');
+
+    for char in text.chars() {
+        match char {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            char if char.is_control() => {
+                let _ = write!(quoted, "\\u{:04X}", u32::from(char));
+            }
+            char => quoted.push(char),
+        }
+    }
+
+    quoted.push('"');
+
+    quoted
+}
+
+/// Every setting as a TOML key/value pair. Keys are always quoted, as paths contain `:`.
+pub fn to_toml(settings: &Settings) -> String {
+    let mut toml = String::new();
+
+    for (key, setting) in settings {
+        let _ = match setting {
+            Setting::Value(value) => {
+                writeln!(toml, "{} = {}", toml_string(key), toml_string(value))
+            }
+            Setting::List(values) => {
+                let _ = writeln!(toml, "{} = [", toml_string(key));
+
+                for value in values {
+                    let _ = writeln!(toml, "    {},", toml_string(value));
+                }
+
+                writeln!(toml, "]")
+            }
+        };
+    }
+
+    toml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::HYPRLAND;
+    use crate::sources::{Source, Sources};
+
+    #[test]
+    fn test_settings() {
+        let sources = Sources::load(Source::new(
+            None,
+            [
+                "$gap = 4",
+                "$outer = $gap",
+                "general {",
+                "    gaps_in = $gap",
+                "    gaps_out = {{$gap * 2}}",
+                "    gaps_in = 2",
+                "}",
+                "decoration:blur:size = 3 # comment",
+                "bind = SUPER, Q, exec, kitty",
+                "bind = SUPER, C, killactive",
+                "plugin {",
+                "    thing = a",
+                "    thing = b",
+                "}",
+                "exec-once = notify-send ##1",
+                "",
+            ]
+            .join("\n"),
+        ));
+        let (statements, _) = sources.statements();
+
+        let settings = settings(&HYPRLAND, &statements);
+
+        assert_eq!(
+            to_json(&settings),
+            serde_json::json!({
+                "$gap": "4",
+                "$outer": "4",
+                "general:gaps_in": "2",
+                "general:gaps_out": "8",
+                "decoration:blur:size": "3",
+                "bind": ["SUPER, Q, exec, kitty", "SUPER, C, killactive"],
+                "plugin:thing": ["a", "b"],
+                "exec-once": ["notify-send #1"],
+            })
+        );
+        assert!(to_toml(&settings).contains("\"bind\" = [\n    \"SUPER, Q, exec, kitty\",\n"));
+    }
+}
@@ -0,0 +1,366 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::variables::Variables;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The expression is not valid, with what went wrong.
+    Syntax(String),
+    /// A variable that is not defined, as it is written, with or without its `$`.
+    UndefinedVariable(String),
+    /// A variable whose value is not a number, with its value.
+    NotANumber(String, String),
+    DivisionByZero,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Syntax(message) => f.write_str(message),
+            Self::UndefinedVariable(name) => write!(f, "undefined variable `{name}`"),
+            Self::NotANumber(name, value) => {
+                write!(f, "`{name}` is `{value}`, which is not a number")
+            }
+            Self::DivisionByZero => f.write_str("division by zero"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Number(&'a str),
+    /// A variable as it is written. Hyprlang names variables without their `$` in expressions,
+    /// but both are accepted.
+    Variable(&'a str),
+    Operator(char),
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token<'_>>, Error> {
+    let mut tokens = vec![];
+    let mut rest = expression.trim_start();
+
+    while let Some(char) = rest.chars().next() {
+        let len = match char {
+            '+' | '-' | '*' | '/' | '(' | ')' => {
+                tokens.push(Token::Operator(char));
+                1
+            }
+            '0'..='9' | '.' => {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                    .unwrap_or(rest.len());
+
+                tokens.push(Token::Number(&rest[..len]));
+                len
+            }
+            '$' | 'a'..='z' | 'A'..='Z' | '_' => {
+                let start = usize::from(char == '$');
+                let len = rest[start..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .map_or(rest.len(), |len| start + len);
+                if len == start {
+                    return Err(Error::Syntax("expected a variable name after `$`".into()));
+                }
+
+                tokens.push(Token::Variable(&rest[..len]));
+                len
+            }
+            _ => return Err(Error::Syntax(format!("unexpected `{char}`"))),
+        };
+
+        rest = rest[len..].trim_start();
+    }
+
+    Ok(tokens)
+}
+
+/// A recursive descent evaluator over the usual precedence levels: `+ -`, then `* /`, then unary
+/// signs and parentheses.
+struct Evaluator<'a, 'b> {
+    tokens: &'b [Token<'a>],
+    position: usize,
+    variables: &'b Variables,
+}
+
+impl<'a> Evaluator<'a, '_> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.position += 1;
+
+        token
+    }
+
+    fn sum(&mut self) -> Result<f64, Error> {
+        let mut value = self.product()?;
+
+        while let Some(Token::Operator(operator @ ('+' | '-'))) = self.peek() {
+            self.position += 1;
+
+            let rhs = self.product()?;
+            value = if operator == '+' {
+                value + rhs
+            } else {
+                value - rhs
+            };
+        }
+
+        Ok(value)
+    }
+
+    fn product(&mut self) -> Result<f64, Error> {
+        let mut value = self.factor()?;
+
+        while let Some(Token::Operator(operator @ ('*' | '/'))) = self.peek() {
+            self.position += 1;
+
+            let rhs = self.factor()?;
+            value = if operator == '*' {
+                value * rhs
+            } else if rhs == 0.0 {
+                return Err(Error::DivisionByZero);
+            } else {
+                value / rhs
+            };
+        }
+
+        Ok(value)
+    }
+
+    fn factor(&mut self) -> Result<f64, Error> {
+        match self.next() {
+            Some(Token::Number(number)) => number
+                .parse()
+                .map_err(|_| Error::Syntax(format!("invalid number `{number}`"))),
+            Some(Token::Variable(name)) => {
+                let value = self
+                    .variables
+                    .get(name)
+                    .ok_or_else(|| Error::UndefinedVariable(name.to_string()))?;
+
+                value
+                    .trim()
+                    .parse()
+                    .map_err(|_| Error::NotANumber(name.to_string(), value.to_string()))
+            }
+            Some(Token::Operator('-')) => Ok(-self.factor()?),
+            Some(Token::Operator('+')) => self.factor(),
+            Some(Token::Operator('(')) => {
+                let value = self.sum()?;
+
+                match self.next() {
+                    Some(Token::Operator(')')) => Ok(value),
+                    _ => Err(Error::Syntax("expected `)`".into())),
+                }
+            }
+            Some(Token::Operator(operator)) => {
+                Err(Error::Syntax(format!("unexpected `{operator}`")))
+            }
+            None => Err(Error::Syntax("unexpected end of expression".into())),
+        }
+    }
+}
+
+/// Evaluate the arithmetic `expression` (the text between `{{` and `}}`), looking up variables
+/// in `variables`.
+pub fn evaluate(expression: &str, variables: &Variables) -> Result<f64, Error> {
+    let tokens = tokenize(expression)?;
+    let mut evaluator = Evaluator {
+        tokens: &tokens,
+        position: 0,
+        variables,
+    };
+
+    let value = evaluator.sum()?;
+
+    match evaluator.peek() {
+        None => Ok(value),
+        Some(_) => Err(Error::Syntax("expected an operator".into())),
+    }
+}
+
+/// The variables `expression` refers to, as they are written, with or without their `$`.
+pub fn names(expression: &str) -> Vec<&str> {
+    tokenize(expression)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Variable(name) => Some(name),
+            _ => None,
+        })
+        .collect()
+}
+
+/// `value` as Hyprland prints it: without a fractional part when it is a whole number.
+pub fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{value:.0}")
+    } else {
+        value.to_string()
+    }
+}
+
+/// The byte range of every `{{ expression }}` in `text`, including the braces. Escaped `\{{`
+/// and unterminated expressions are skipped.
+pub fn expressions(text: &str) -> Vec<Range<usize>> {
+    let mut found = vec![];
+    let mut from = 0;
+
+    while let Some(start) = text[from..].find("{{").map(|idx| from + idx) {
+        if text[..start].ends_with('\\') {
+            from = start + 2;
+            continue;
+        }
+
+        let Some(len) = text[start + 2..].find("}}") else {
+            break;
+        };
+
+        let end = start + 2 + len + 2;
+        found.push(start..end);
+        from = end;
+    }
+
+    found
+}
+
+/// The text between the braces of an expression found by [`expressions`].
+pub fn inner(text: &str, range: Range<usize>) -> &str {
+    &text[range.start + 2..range.end - 2]
+}
+
+/// `expression` with one space around binary operators, and none after unary signs or inside
+/// parentheses. The padding inside the braces is kept as it is written. Expressions that don't
+/// tokenize, or that have two operands in a row like `{{ foo bar }}`, are kept as they are, as
+/// joining the operands would change their meaning.
+pub fn normalize(expression: &str) -> String {
+    let Ok(tokens) = tokenize(expression) else {
+        return expression.to_string();
+    };
+
+    let trimmed = expression.trim_start();
+    let mut normalized = String::with_capacity(expression.len());
+    normalized.push_str(&expression[..expression.len() - trimmed.len()]);
+    // Whether the previous token ends an operand, so a following sign is a binary operator
+    let mut after_operand = false;
+
+    for token in tokens {
+        match token {
+            Token::Number(_) | Token::Variable(_) | Token::Operator('(') if after_operand => {
+                return expression.to_string();
+            }
+            Token::Number(text) | Token::Variable(text) => {
+                normalized.push_str(text);
+                after_operand = true;
+            }
+            Token::Operator(')') => {
+                normalized.push(')');
+                after_operand = true;
+            }
+            Token::Operator('(') => {
+                normalized.push('(');
+                after_operand = false;
+            }
+            Token::Operator(operator) if after_operand => {
+                normalized.push(' ');
+                normalized.push(operator);
+                normalized.push(' ');
+                after_operand = false;
+            }
+            Token::Operator(operator) => normalized.push(operator),
+        }
+    }
+
+    normalized.push_str(&trimmed[trimmed.trim_end().len()..]);
+
+    normalized
+}
+
+/// `text` with the inside of every expression normalized, see [`normalize`].
+pub fn normalize_all(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+
+    for range in expressions(text) {
+        result.push_str(&text[last..range.start]);
+        result.push_str("{{");
+        result.push_str(&normalize(inner(text, range.clone())));
+        result.push_str("}}");
+        last = range.end;
+    }
+
+    result.push_str(&text[last..]);
+
+    result
+}
+
+/// Replace every `{{ expression }}` in `text` with its value. Escaped `\{{` is kept as a literal
+/// `{{`.
+pub fn expand(text: &str, variables: &Variables) -> Result<String, Error> {
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+
+    for range in expressions(text) {
+        result.push_str(&text[last..range.start]);
+        result.push_str(&format_number(evaluate(
+            inner(text, range.clone()),
+            variables,
+        )?));
+        last = range.end;
+    }
+
+    result.push_str(&text[last..]);
+
+    Ok(result.replace("\\{{", "{{"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate() {
+        let mut variables = Variables::default();
+        variables.define("$gap", "5");
+        variables.define("$name", "kitty");
+
+        assert_eq!(evaluate("$gap * 2", &variables), Ok(10.0));
+        assert_eq!(evaluate("-(gap + 1) / 4 + 0.5", &variables), Ok(-1.0));
+        assert_eq!(
+            evaluate("1 / (gap - 5)", &variables),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            evaluate("nope + 1", &variables),
+            Err(Error::UndefinedVariable("nope".into()))
+        );
+        assert!(matches!(
+            evaluate("$name + 1", &variables),
+            Err(Error::NotANumber(..))
+        ));
+        assert!(matches!(evaluate("1 +", &variables), Err(Error::Syntax(_))));
+
+        assert_eq!(
+            expand("{{$gap*2}}, {{ 1 / 4 }} \\{{literal}}", &variables).as_deref(),
+            Ok("10, 0.25 {{literal}}")
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(" $a*-(2+b )/ 1.50"), " $a * -(2 + b) / 1.50");
+        assert_eq!(normalize("  1+2 "), "  1 + 2 ");
+        assert_eq!(normalize(" 1 % 2 "), " 1 % 2 ");
+        assert_eq!(normalize(" foo bar "), " foo bar ");
+        assert_eq!(normalize(" 1 2 "), " 1 2 ");
+        assert_eq!(normalize("2(3)"), "2(3)");
+        assert_eq!(
+            normalize_all("exec = echo {{ foo bar }} {{1+2}} \\{{ x }}"),
+            "exec = echo {{ foo bar }} {{1 + 2}} \\{{ x }}"
+        );
+    }
+}
@@ -0,0 +1,30 @@
+#![warn(clippy::cargo)]
+#![warn(clippy::complexity)]
+#![warn(clippy::correctness)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::perf)]
+#![warn(clippy::style)]
+#![warn(clippy::suspicious)]
+#![allow(clippy::missing_errors_doc)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::must_use_candidate)]
+
+pub mod ast;
+pub mod cheatsheet;
+pub mod config;
+pub mod cst;
+pub mod diagnostic;
+pub mod dump;
+pub mod expression;
+pub mod grammar;
+pub mod lint;
+pub mod lsp;
+pub mod migrate;
+pub mod parse;
+pub mod parsed;
+pub mod report;
+pub mod schema;
+pub mod semdiff;
+pub mod sources;
+pub mod variables;
+pub mod verify;
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use crate::diagnostic::{Diagnostic, Location, Severity};
+use crate::parsed::bind::canonical_mods;
+use crate::sources::Statement;
+use crate::variables::Variables;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct BindKey {
+    flags: Vec<char>,
+    mods: Vec<String>,
+    key: String,
+    submap: Option<String>,
+}
+
+impl BindKey {
+    fn describe(&self, key: &str) -> String {
+        let description = format!("{}, {key}", self.mods.join(" "));
+
+        match &self.submap {
+            Some(submap) => format!("{description} in submap \"{submap}\""),
+            None => description,
+        }
+    }
+
+    fn matches_unbind(&self, other: &Self) -> bool {
+        self.mods == other.mods && self.key == other.key && self.submap == other.submap
+    }
+}
+
+/// Report binds that map the same flags, modifiers and key in the same submap as an earlier
+/// bind. Hyprland does not complain about these and silently picks one.
+pub fn duplicate_binds(statements: &[Statement]) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    let mut variables = Variables::default();
+    let mut submap: Option<String> = None;
+    let mut seen: HashMap<BindKey, Location> = HashMap::new();
+
+    for statement in statements {
+        let Some(info) = statement.line.as_sectionable() else {
+            continue;
+        };
+        let rhs = info.rhs.unwrap_or_default();
+
+        if info.lhs.starts_with('$') {
+            variables.define(info.lhs, rhs);
+            continue;
+        }
+
+        if info.indent == 0 && info.lhs == "submap" {
+            let name = rhs.split(',').next().unwrap_or_default().trim();
+
+            submap = (!name.is_empty() && name != "reset").then(|| name.to_string());
+            continue;
+        }
+
+        let location = statement
+            .source
+            .span(info.lhs, info.rhs.unwrap_or(info.lhs));
+
+        if info.lhs == "unbind" {
+            let mut fields = rhs.split(',').map(str::trim);
+            let unbound = BindKey {
+                flags: vec![],
+                mods: canonical_mods(&variables.substitute(fields.next().unwrap_or_default())),
+                key: fields.next().unwrap_or_default().to_lowercase(),
+                submap: submap.clone(),
+            };
+
+            seen.retain(|key, _| !key.matches_unbind(&unbound));
+            continue;
+        }
+
+        let Some(bind) = &info.bind else {
+            continue;
+        };
+
+        let mut flags = bind.flags.chars().collect::<Vec<_>>();
+        flags.sort_unstable();
+        flags.dedup();
+
+        let key = BindKey {
+            flags,
+            mods: canonical_mods(&variables.substitute(bind.mods_str)),
+            key: bind.key.to_lowercase(),
+            submap: submap.clone(),
+        };
+
+        match seen.get(&key) {
+            Some(first) => diagnostics.push(
+                Diagnostic::new(
+                    "duplicate-bind",
+                    Severity::Warning,
+                    location,
+                    format!("{} is bound more than once", key.describe(bind.key)),
+                )
+                .with_related(first.clone(), "first bound here"),
+            ),
+            None => {
+                seen.insert(key, location);
+            }
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sources::{Source, Sources};
+
+    fn lint(text: &str) -> Vec<Diagnostic> {
+        let sources = Sources::load(Source::new(None, text.to_string()));
+        let (statements, _) = sources.statements();
+
+        duplicate_binds(&statements)
+    }
+
+    #[test]
+    fn test_duplicate_binds() {
+        let diagnostics = lint(
+            "$mod = SUPER\nbind = $mod, Q, exec, kitty\nbind = super, q, killactive,\nbindl = SUPER, Q, exec, x\n",
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location.start.line, 3);
+        assert_eq!(diagnostics[0].related[0].0.start.line, 2);
+    }
+
+    #[test]
+    fn test_duplicate_binds_respects_submaps_and_unbind() {
+        let diagnostics = lint(
+            "bind = , escape, exec, a\nsubmap = resize\nbind = , escape, submap, reset\nsubmap = reset\nunbind = , escape\nbind = , escape, exec, b\n",
+        );
+
+        assert_eq!(diagnostics, []);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::expression::{Error, evaluate, expand, expressions as find_expressions, inner};
+use crate::sources::Statement;
+use crate::variables::Variables;
+
+/// Report `{{ }}` expressions that divide by zero, name undefined variables, or can't be
+/// evaluated.
+///
+/// Variables written with their `$` are left to the variables lint.
+pub fn expressions(statements: &[Statement]) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let mut variables = Variables::default();
+
+    for statement in statements {
+        let Some(info) = statement.line.as_sectionable() else {
+            continue;
+        };
+        let Some(rhs) = info.rhs else {
+            continue;
+        };
+
+        for range in find_expressions(rhs) {
+            let (code, message) = match evaluate(inner(rhs, range.clone()), &variables) {
+                Ok(_) => continue,
+                Err(Error::UndefinedVariable(name)) if name.starts_with('$') => continue,
+                Err(Error::DivisionByZero) => ("division-by-zero", Error::DivisionByZero),
+                Err(e @ Error::UndefinedVariable(_)) => ("undefined-variable", e),
+                Err(e) => ("invalid-expression", e),
+            };
+
+            diagnostics.push(Diagnostic::new(
+                code,
+                Severity::Error,
+                statement.source.location(&rhs[range]),
+                message.to_string(),
+            ));
+        }
+
+        if info.lhs.starts_with('$') {
+            let value = variables.substitute(rhs);
+            variables.define(info.lhs, &expand(&value, &variables).unwrap_or(value));
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sources::{Source, Sources};
+
+    #[test]
+    fn test_expressions() {
+        let sources = Sources::load(Source::new(
+            None,
+            [
+                "$gap = 4",
+                "$double = {{gap * 2}}",
+                "$name = kitty",
+                "general {",
+                "    gaps_in = {{ double / (gap - 4) }}",
+                "    gaps_out = {{ (gap + 1) * 2 }}, {{ nope }}",
+                "    border_size = {{ name + 1 }}",
+                "    col.active_border = {{ $undefined + 1 }}",
+                "}",
+                "",
+            ]
+            .join("\n"),
+        ));
+        let (statements, _) = sources.statements();
+
+        assert_eq!(
+            expressions(&statements)
+                .iter()
+                .map(|diagnostic| (
+                    diagnostic.code,
+                    diagnostic.location.start.line,
+                    diagnostic.message.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                ("division-by-zero", 5, "division by zero"),
+                ("undefined-variable", 6, "undefined variable `nope`"),
+                (
+                    "invalid-expression",
+                    7,
+                    "`name` is `kitty`, which is not a number"
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, Location, Position, Severity};
+use crate::parsed::format::format_file;
+use crate::sources::Source;
+
+/// Report a file that formatting with `config` would change, at the first line that changes.
+///
+/// Files that don't parse are left to the parse error.
+pub fn would_reformat(source: &Source, config: Config) -> Option<Diagnostic> {
+    let formatted = format_file(&source.text, config).ok()?;
+    if formatted == source.text {
+        return None;
+    }
+
+    let line = source
+        .text
+        .split_inclusive('\n')
+        .zip(formatted.split_inclusive('\n'))
+        .take_while(|(original, formatted)| original == formatted)
+        .count()
+        + 1;
+    let position = Position { line, column: 1 };
+
+    Some(Diagnostic::new(
+        "would-reformat",
+        Severity::Note,
+        Location {
+            path: source.path.clone(),
+            start: position,
+            end: position,
+        },
+        "file is not formatted",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_would_reformat() {
+        let config = Config::default();
+
+        let formatted = Source::new(None, "a = 1\n".to_string());
+        assert_eq!(would_reformat(&formatted, config), None);
+
+        let unformatted = Source::new(None, "a = 1\nbb=2\n".to_string());
+        let diagnostic = would_reformat(&unformatted, config).unwrap();
+        assert_eq!(diagnostic.location.start.line, 1);
+    }
+}
@@ -0,0 +1,52 @@
+pub mod binds;
+pub mod expressions;
+pub mod format;
+pub mod unknown;
+pub mod values;
+pub mod variables;
+
+use crate::diagnostic::Diagnostic;
+use crate::parsed::line::Line;
+use crate::schema::Dialect;
+use crate::sources::{Sources, Statement};
+
+/// Run every lint over a file and the files it sources, all written for `dialect`.
+pub fn lint(sources: &Sources, dialect: Dialect) -> Vec<Diagnostic> {
+    let (statements, mut diagnostics) = sources.statements();
+
+    diagnostics.extend(sources.diagnostics.iter().cloned());
+    diagnostics.extend(binds::duplicate_binds(&statements));
+    diagnostics.extend(variables::variables(&statements));
+    diagnostics.extend(expressions::expressions(&statements));
+    diagnostics.extend(values::invalid_values(dialect.schema(), &statements));
+    diagnostics.extend(unknown::unknown_keys(dialect.schema(), &statements));
+
+    diagnostics.sort_by(|a, b| a.location.cmp(&b.location));
+
+    diagnostics
+}
+
+/// Each statement along with the names of the categories it is nested in. A category's own start
+/// and end lines are paired with the categories around it.
+pub fn with_categories<'a, 'b>(
+    statements: &'b [Statement<'a>],
+) -> Vec<(Vec<&'a str>, &'b Statement<'a>)> {
+    let mut categories = vec![];
+
+    statements
+        .iter()
+        .map(|statement| {
+            if let Line::CategoryEnd(_) = statement.line {
+                categories.pop();
+            }
+
+            let paired = (categories.clone(), statement);
+
+            if let Line::CategoryStart(info) = &statement.line {
+                categories.push(info.lhs);
+            }
+
+            paired
+        })
+        .collect()
+}
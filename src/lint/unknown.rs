@@ -0,0 +1,183 @@
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parsed::line::Line;
+use crate::schema::{Schema, path};
+use crate::sources::Statement;
+
+use super::with_categories;
+
+/// The number of single character edits that turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// The candidate closest to `name`, if it is close enough to be a likely typo.
+fn suggestion<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+/// Report categories and options that `schema` doesn't know, including ones given as colon
+/// separated paths like `decoration:blur:size`, suggesting the closest known name.
+///
+/// Anything inside an unknown category is only reported once, for the category.
+pub fn unknown_keys(schema: &Schema, statements: &[Statement]) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    for (categories, statement) in with_categories(statements) {
+        let containing = categories.join(":");
+        if !containing.is_empty()
+            && (schema.is_open(&containing) || !schema.is_category(&containing))
+        {
+            continue;
+        }
+
+        let (key, is_category) = match &statement.line {
+            Line::CategoryStart(info) => (info.lhs, true),
+            Line::Sectioned(info) if !info.lhs.starts_with('$') && info.bind.is_none() => {
+                (info.lhs, false)
+            }
+            _ => continue,
+        };
+
+        let full = path(&categories, key);
+        let parts = full.split(':').collect::<Vec<_>>();
+
+        // The first category along the path that is open or unknown
+        let stop = (1..parts.len()).find_map(|len| {
+            let prefix = parts[..len].join(":");
+            (schema.is_open(&prefix) || !schema.is_category(&prefix)).then_some((len, prefix))
+        });
+
+        let (len, is_unknown_category) = match stop {
+            Some((_, prefix)) if schema.is_open(&prefix) => continue,
+            Some((len, _)) => (len, true),
+            None if schema.is_open(&full) => continue,
+            None if is_category && !schema.is_category(&full) => (parts.len(), true),
+            None if !is_category && schema.get(&full).is_none() => (parts.len(), false),
+            None => continue,
+        };
+
+        let unknown_path = parts[..len].join(":");
+        let parent = parts[..len - 1].join(":");
+        let name = parts[len - 1];
+
+        let (code, what, candidates) = if is_unknown_category {
+            let siblings = schema
+                .categories()
+                .into_iter()
+                .filter_map(|category| {
+                    let (category_parent, category_name) =
+                        category.rsplit_once(':').unwrap_or(("", category));
+                    (category_parent == parent).then(|| category_name.to_string())
+                })
+                .collect::<Vec<_>>();
+
+            ("unknown-category", "category", siblings)
+        } else {
+            let options = schema
+                .specs_in(&parent)
+                .map(|(relative, _)| relative)
+                .filter(|relative| !relative.contains(':'))
+                .collect::<Vec<_>>();
+
+            ("unknown-option", "option", options)
+        };
+
+        let message = match suggestion(name, candidates.iter().map(String::as_str)) {
+            Some(suggestion) => {
+                format!("unknown {what} {unknown_path}, did you mean `{suggestion}`?")
+            }
+            None => format!("unknown {what} {unknown_path}"),
+        };
+
+        diagnostics.push(Diagnostic::new(
+            code,
+            Severity::Warning,
+            statement.source.location(key),
+            message,
+        ));
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::HYPRLAND;
+    use crate::sources::{Source, Sources};
+
+    #[test]
+    fn test_unknown_keys() {
+        let sources = Sources::load(Source::new(
+            None,
+            [
+                "decoraton {",
+                "    rounding = 10",
+                "}",
+                "decoration {",
+                "    rouding = 10",
+                "    blur:sise = 3",
+                "}",
+                "general:snap:enabld = true",
+                "plugin {",
+                "    hyprbars {",
+                "        bar_height = 20",
+                "    }",
+                "}",
+                "device[mouse]:sensitivity = 1",
+                "bindel = , XF86AudioRaiseVolume, exec, wpctl",
+                "$var = 1",
+                "monitr = , preferred, auto, 1",
+                "",
+            ]
+            .join("\n"),
+        ));
+        let (statements, _) = sources.statements();
+
+        let diagnostics = unknown_keys(&HYPRLAND, &statements);
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| (diagnostic.location.start.line, diagnostic.message.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (1, "unknown category decoraton, did you mean `decoration`?"),
+                (
+                    5,
+                    "unknown option decoration:rouding, did you mean `rounding`?"
+                ),
+                (
+                    6,
+                    "unknown option decoration:blur:sise, did you mean `size`?"
+                ),
+                (
+                    8,
+                    "unknown option general:snap:enabld, did you mean `enabled`?"
+                ),
+                (17, "unknown option monitr, did you mean `monitor`?"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::expression::expand;
+use crate::schema::{Schema, path};
+use crate::sources::Statement;
+use crate::variables::Variables;
+
+use super::with_categories;
+
+/// Report option values that don't match the type of the option in `schema`.
+///
+/// Variables are substituted and expressions evaluated first. Values using undefined variables or
+/// expressions that can't be evaluated are skipped, as are options the schema doesn't know.
+pub fn invalid_values(schema: &Schema, statements: &[Statement]) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let mut variables = Variables::default();
+
+    for (categories, statement) in with_categories(statements) {
+        let Some(info) = statement.line.as_sectionable() else {
+            continue;
+        };
+        let Some(rhs) = info.rhs else {
+            continue;
+        };
+
+        if info.lhs.starts_with('$') {
+            let value = variables.substitute(rhs);
+            variables.define(info.lhs, &expand(&value, &variables).unwrap_or(value));
+            continue;
+        }
+
+        let Some(spec) = schema.get(&path(&categories, info.lhs)) else {
+            continue;
+        };
+
+        let value = variables.substitute(rhs);
+        let value = expand(&value, &variables).unwrap_or(value);
+        if value.contains('$') || value.contains("{{") || spec.ty.accepts(&value) {
+            continue;
+        }
+
+        diagnostics.push(Diagnostic::new(
+            "invalid-value",
+            Severity::Error,
+            statement.source.location(rhs),
+            format!(
+                "invalid value `{}` for {}: expected {}",
+                value.trim(),
+                spec.path,
+                spec.ty
+            ),
+        ));
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::HYPRLAND;
+    use crate::sources::{Source, Sources};
+
+    #[test]
+    fn test_invalid_values() {
+        let sources = Sources::load(Source::new(
+            None,
+            [
+                "$gaps = 5",
+                "general {",
+                "    gaps_in = abc",
+                "    gaps_out = $gaps",
+                "    border_size = {{$gaps * 2}}",
+                "    snap {",
+                "        enabled = maybe",
+                "    }",
+                "}",
+                "# gaps",
+                "device {",
+                "    name = mouse",
+                "    sensitivity = fast",
+                "}",
+                "",
+            ]
+            .join("\n"),
+        ));
+        let (statements, _) = sources.statements();
+
+        let diagnostics = invalid_values(&HYPRLAND, &statements);
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| (diagnostic.location.start.line, diagnostic.message.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (3, "invalid value `abc` for general:gaps_in: expected gaps"),
+                (
+                    7,
+                    "invalid value `maybe` for general:snap:enabled: expected bool"
+                ),
+                (
+                    13,
+                    "invalid value `fast` for input:sensitivity: expected float"
+                ),
+            ]
+        );
+    }
+}
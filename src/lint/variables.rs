@@ -0,0 +1,163 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::diagnostic::{Diagnostic, Location, Severity};
+use crate::expression::{expressions, inner, names};
+use crate::parsed::bind::Bind;
+use crate::sources::Statement;
+use crate::variables::{longest_match, references};
+
+/// The part of `rhs` that is run by a shell: all of it for the `exec` keywords, or the arguments
+/// of a bind to the `exec` dispatchers.
+fn shell_command<'a>(keyword: &'a str, rhs: &'a str) -> Option<&'a str> {
+    if keyword.starts_with("exec") {
+        return Some(rhs);
+    }
+
+    Bind::parse(keyword, rhs)
+        .filter(|bind| matches!(bind.dispatcher, "exec" | "execr"))
+        .and_then(|bind| bind.args)
+}
+
+/// Whether `slice` is a part of `text`.
+fn is_within(text: &str, slice: &str) -> bool {
+    text.as_bytes().as_ptr_range().contains(&slice.as_ptr())
+}
+
+/// Report variables that are used before or without being defined, defined but never used, or
+/// defined more than once.
+///
+/// Undefined variables in shell commands are not reported, as they may be meant for the shell,
+/// like `$HOME` in `exec-once = notify-send $HOME`.
+pub fn variables(statements: &[Statement]) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    let mut first_definitions: HashMap<&str, Location> = HashMap::new();
+    for statement in statements {
+        if let Some(info) = statement.line.as_sectionable()
+            && let Some(name) = info.lhs.strip_prefix('$')
+        {
+            first_definitions
+                .entry(name)
+                .or_insert_with(|| statement.source.location(info.lhs));
+        }
+    }
+
+    let mut defined: HashMap<&str, Location> = HashMap::new();
+    let mut used: HashSet<&str> = HashSet::new();
+
+    for statement in statements {
+        let Some(info) = statement.line.as_sectionable() else {
+            continue;
+        };
+
+        let command = shell_command(info.lhs, info.rhs.unwrap_or_default());
+
+        for reference in references(info.rhs.unwrap_or_default()) {
+            let ident = &reference[1..];
+            let location = statement.source.location(reference);
+
+            if let Some(len) = longest_match(ident, |name| defined.contains_key(name)) {
+                used.insert(&ident[..len]);
+            } else if let Some(len) =
+                longest_match(ident, |name| first_definitions.contains_key(name))
+            {
+                let name = &ident[..len];
+                used.insert(name);
+
+                diagnostics.push(
+                    Diagnostic::new(
+                        "used-before-definition",
+                        Severity::Warning,
+                        location,
+                        format!("${name} is used before it is defined"),
+                    )
+                    .with_related(first_definitions[name].clone(), "defined here"),
+                );
+            } else if !command.is_some_and(|command| is_within(command, reference)) {
+                diagnostics.push(Diagnostic::new(
+                    "undefined-variable",
+                    Severity::Error,
+                    location,
+                    format!("${ident} is not defined"),
+                ));
+            }
+        }
+
+        // Expressions can name variables without their `$`, which are checked by the
+        // expression lint
+        let rhs = info.rhs.unwrap_or_default();
+        for range in expressions(rhs) {
+            used.extend(
+                names(inner(rhs, range))
+                    .into_iter()
+                    .filter(|name| first_definitions.contains_key(name)),
+            );
+        }
+
+        let Some(name) = info.lhs.strip_prefix('$') else {
+            continue;
+        };
+        let location = statement.source.location(info.lhs);
+
+        if let Some(previous) = defined.insert(name, location.clone()) {
+            diagnostics.push(
+                Diagnostic::new(
+                    "redefined-variable",
+                    Severity::Warning,
+                    location,
+                    format!("${name} is already defined"),
+                )
+                .with_related(previous, "previously defined here"),
+            );
+        }
+    }
+
+    for (name, location) in first_definitions {
+        if !used.contains(name) {
+            diagnostics.push(Diagnostic::new(
+                "unused-variable",
+                Severity::Warning,
+                location,
+                format!("${name} is never used"),
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sources::{Source, Sources};
+
+    fn lint(text: &str) -> Vec<(&'static str, usize)> {
+        let sources = Sources::load(Source::new(None, text.to_string()));
+        let (statements, _) = sources.statements();
+
+        let mut found = variables(&statements)
+            .into_iter()
+            .map(|diagnostic| (diagnostic.code, diagnostic.location.start.line))
+            .collect::<Vec<_>>();
+        found.sort_unstable();
+
+        found
+    }
+
+    #[test]
+    fn test_variables() {
+        let found = lint(
+            "bind = $mod, Q, exec, $terminal\n$mod = SUPER\n$mod = ALT\n$unused = 1\nbind = $modSHIFT $nope, E, exec, echo $USER\n$gap = 2\ngaps_in = {{gap * 2}}\nexec-once = notify-send $HOME $gap\n",
+        );
+
+        assert_eq!(
+            found,
+            [
+                ("redefined-variable", 3),
+                ("undefined-variable", 5),
+                ("unused-variable", 4),
+                ("used-before-definition", 1),
+            ]
+        );
+    }
+}
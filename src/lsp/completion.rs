@@ -0,0 +1,188 @@
+use serde_json::{Value, json};
+
+use crate::schema::{Schema, Spec, Type, path};
+
+use super::edits::split_lines;
+
+/// REF
+/// - <https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#completionItemKind>
+const COMPLETION_KIND_PROPERTY: u8 = 10;
+const COMPLETION_KIND_MODULE: u8 = 9;
+const COMPLETION_KIND_KEYWORD: u8 = 14;
+const COMPLETION_KIND_VALUE: u8 = 12;
+
+/// `line` without its comment, keeping escaped `##`.
+fn strip_comment(line: &str) -> &str {
+    let mut from = 0;
+
+    while let Some(idx) = line[from..].find('#').map(|idx| from + idx) {
+        if !line[idx + 1..].starts_with('#') {
+            return &line[..idx];
+        }
+
+        from = idx + 2;
+    }
+
+    line
+}
+
+/// The names of the categories that are open at the start of line `line` of `text`.
+///
+/// This scans the text rather than parsing it, because the document is usually incomplete while
+/// it is being edited.
+pub fn category_path(text: &str, line: usize) -> Vec<&str> {
+    let mut categories = vec![];
+
+    for line in split_lines(text).into_iter().take(line) {
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let line = strip_comment(line).trim_end();
+
+        if let Some(name) = line.strip_suffix('{') {
+            categories.push(name.trim());
+        } else if line.starts_with('}') {
+            categories.pop();
+        }
+    }
+
+    categories
+}
+
+fn documentation(spec: &Spec) -> Value {
+    json!({ "kind": "markdown", "value": spec.documentation() })
+}
+
+/// Completion items for the text before the cursor, `before`, on line `line` of `text`.
+///
+/// Keys are completed from the options and sub-categories of the enclosing category, and values
+/// from the choices of enum and bool options.
+pub fn completions(schema: &Schema, text: &str, line: usize, before: &str) -> Value {
+    let categories = category_path(text, line);
+    let category = categories.join(":");
+
+    if let Some((key, _)) = before.split_once('=') {
+        let Some(spec) = schema.get(&path(&categories, key.trim())) else {
+            return json!([]);
+        };
+
+        let values: &[&str] = match spec.ty {
+            Type::Bool => &["true", "false"],
+            Type::Enum(values) => values,
+            _ => &[],
+        };
+
+        return values
+            .iter()
+            .map(|value| {
+                json!({
+                    "label": value,
+                    "kind": COMPLETION_KIND_VALUE,
+                    "documentation": documentation(spec),
+                })
+            })
+            .collect();
+    }
+
+    let options = schema
+        .specs_in(&category)
+        .filter(|(relative, _)| !relative.contains(':'))
+        .map(|(relative, spec)| {
+            json!({
+                "label": relative,
+                "kind": match spec.ty {
+                    Type::Keyword => COMPLETION_KIND_KEYWORD,
+                    _ => COMPLETION_KIND_PROPERTY,
+                },
+                "detail": spec.ty.to_string(),
+                "documentation": documentation(spec),
+            })
+        });
+
+    let subcategories = schema.categories().into_iter().filter_map(|path| {
+        let name = match category.as_str() {
+            "" => path,
+            _ => path.strip_prefix(&category)?.strip_prefix(':')?,
+        };
+
+        (!name.contains(':')).then(|| {
+            json!({
+                "label": name,
+                "kind": COMPLETION_KIND_MODULE,
+                "detail": "category",
+            })
+        })
+    });
+
+    options.chain(subcategories).collect()
+}
+
+/// Hover documentation for the key at `character` (in bytes) on line `line` of `text`.
+pub fn hover(schema: &Schema, text: &str, line: usize, character: usize) -> Value {
+    let Some(current) = split_lines(text).get(line).copied() else {
+        return Value::Null;
+    };
+    let Some((key, _)) = current.split_once('=') else {
+        return Value::Null;
+    };
+
+    let start = key.len() - key.trim_start().len();
+    let end = key.trim_end().len();
+    if !(start..=end).contains(&character) {
+        return Value::Null;
+    }
+
+    let path = path(&category_path(text, line), key.trim());
+
+    schema.get(&path).map_or(Value::Null, |spec| {
+        json!({
+            "contents": documentation(spec),
+            "range": {
+                "start": { "line": line, "character": key[..start].encode_utf16().count() },
+                "end": { "line": line, "character": key[..end].encode_utf16().count() },
+            },
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::HYPRLAND;
+
+    const TEXT: &str = "general { # comment\n  gaps_in = 5\n}\ndecoration {\n  blur {\n    \n";
+
+    #[test]
+    fn test_category_path() {
+        assert_eq!(category_path(TEXT, 1), ["general"]);
+        assert_eq!(category_path(TEXT, 3), Vec::<&str>::new());
+        assert_eq!(category_path(TEXT, 5), ["decoration", "blur"]);
+    }
+
+    #[test]
+    fn test_completions_and_hover() {
+        let completions = completions(&HYPRLAND, TEXT, 5, "    ");
+        let labels = completions
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["label"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert!(labels.contains(&"vibrancy"));
+        assert!(!labels.contains(&"rounding"));
+
+        let values = super::completions(&HYPRLAND, TEXT, 5, "    enabled = ");
+        assert_eq!(values[0]["label"], "true");
+
+        let hover = hover(&HYPRLAND, TEXT, 1, 4);
+        assert!(
+            hover["contents"]["value"]
+                .as_str()
+                .unwrap()
+                .starts_with("**general:gaps_in** `gaps`")
+        );
+        assert_eq!(super::hover(&HYPRLAND, TEXT, 1, 12), Value::Null);
+    }
+}
@@ -0,0 +1,190 @@
+use serde_json::{Value, json};
+
+use crate::diagnostic::Position;
+use crate::sources::Source;
+
+/// A replacement of the original lines `start..end` by `lines`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hunk<'a> {
+    pub start: usize,
+    pub end: usize,
+    pub lines: Vec<&'a str>,
+}
+
+/// The lines of `text`, each with its trailing newline, so that they concatenate back to `text`.
+pub fn split_lines(text: &str) -> Vec<&str> {
+    text.split_inclusive('\n').collect()
+}
+
+/// The hunks that turn `old` into `new`, based on their longest common subsequence of lines.
+pub fn diff<'a>(old: &[&str], new: &[&'a str]) -> Vec<Hunk<'a>> {
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    // lcs[i][j] is the length of the longest common subsequence of old_mid[i..] and new_mid[j..]
+    let width = new_mid.len() + 1;
+    let mut lcs = vec![0_u32; (old_mid.len() + 1) * width];
+
+    for i in (0..old_mid.len()).rev() {
+        for j in (0..new_mid.len()).rev() {
+            lcs[i * width + j] = if old_mid[i] == new_mid[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut hunks: Vec<Hunk> = vec![];
+    let mut current: Option<Hunk> = None;
+
+    let (mut i, mut j) = (0, 0);
+    while i < old_mid.len() || j < new_mid.len() {
+        if i < old_mid.len() && j < new_mid.len() && old_mid[i] == new_mid[j] {
+            hunks.extend(current.take());
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        let hunk = current.get_or_insert_with(|| Hunk {
+            start: prefix + i,
+            end: prefix + i,
+            lines: vec![],
+        });
+
+        if j == new_mid.len()
+            || (i < old_mid.len() && lcs[(i + 1) * width + j] >= lcs[i * width + j + 1])
+        {
+            hunk.end += 1;
+            i += 1;
+        } else {
+            hunk.lines.push(new_mid[j]);
+            j += 1;
+        }
+    }
+
+    hunks.extend(current);
+
+    hunks
+}
+
+/// The LSP position at the start of line `idx` of `lines`, or the end of the document.
+pub fn line_start(lines: &[&str], idx: usize) -> Value {
+    match lines.last() {
+        Some(last) if idx >= lines.len() && !last.ends_with('\n') => json!({
+            "line": lines.len() - 1,
+            "character": last.encode_utf16().count(),
+        }),
+        _ => json!({ "line": idx, "character": 0 }),
+    }
+}
+
+pub fn text_edit(lines: &[&str], hunk: &Hunk) -> Value {
+    json!({
+        "range": {
+            "start": line_start(lines, hunk.start),
+            "end": line_start(lines, hunk.end),
+        },
+        "newText": hunk.lines.concat(),
+    })
+}
+
+/// Convert a 1-based line and char column into a 0-based LSP position counted in UTF-16.
+pub fn lsp_position(text: &str, position: Position) -> Value {
+    let line = position.line.saturating_sub(1);
+    let character = text
+        .lines()
+        .nth(line)
+        .unwrap_or_default()
+        .chars()
+        .take(position.column.saturating_sub(1))
+        .map(char::len_utf16)
+        .sum::<usize>();
+
+    json!({ "line": line, "character": character })
+}
+
+/// The LSP range covering `slice`, which must borrow from the source text.
+pub fn lsp_range(source: &Source, slice: &str) -> Value {
+    lsp_span(source, slice, slice)
+}
+
+/// The LSP range from the start of `first` to the end of `last`, both borrowed from the source
+/// text.
+pub fn lsp_span(source: &Source, first: &str, last: &str) -> Value {
+    let location = source.span(first, last);
+
+    json!({
+        "start": lsp_position(&source.text, location.start),
+        "end": lsp_position(&source.text, location.end),
+    })
+}
+
+/// Convert a 0-based LSP line and UTF-16 character into a byte offset in `text`.
+pub fn byte_offset(text: &str, line: usize, character: usize) -> usize {
+    let line_start = split_lines(text)
+        .iter()
+        .take(line)
+        .map(|line| line.len())
+        .sum::<usize>();
+
+    let mut units = 0;
+
+    text[line_start..]
+        .char_indices()
+        .take_while(|(_, c)| *c != '\n')
+        .find(|(_, c)| {
+            units += c.len_utf16();
+            units > character
+        })
+        .map_or_else(
+            || {
+                line_start
+                    + text[line_start..]
+                        .find('\n')
+                        .unwrap_or(text.len() - line_start)
+            },
+            |(idx, _)| line_start + idx,
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let old = ["a\n", "b\n", "c\n", "d\n"];
+        let new = ["a\n", "B\n", "c\n", "x\n", "d\n"];
+
+        assert_eq!(
+            diff(&old, &new),
+            [
+                Hunk {
+                    start: 1,
+                    end: 2,
+                    lines: vec!["B\n"],
+                },
+                Hunk {
+                    start: 3,
+                    end: 3,
+                    lines: vec!["x\n"],
+                },
+            ]
+        );
+        assert_eq!(diff(&old, &old), []);
+    }
+}
@@ -0,0 +1,709 @@
+pub mod completion;
+pub mod edits;
+pub mod symbols;
+pub mod transport;
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::ptr;
+
+use serde_json::{Value, json};
+
+use crate::config::{Config, IndentMode};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parsed::format::format_file_recovering;
+use crate::schema::{Dialect, Schema};
+use crate::sources::{Source, Sources};
+use crate::variables::occurrences;
+
+use completion::{completions, hover};
+use edits::{byte_offset, diff, lsp_position, lsp_range, split_lines, text_edit};
+use symbols::{document_symbols, folding_ranges};
+use transport::{read_message, write_message};
+
+const INVALID_PARAMS: i64 = -32602;
+const METHOD_NOT_FOUND: i64 = -32601;
+
+type Response = Result<Value, (i64, String)>;
+
+/// A language server for a single client, holding the documents the client has opened.
+#[derive(Debug, Default)]
+pub struct Server {
+    config: Config,
+    /// The dialect of every document, instead of detecting it from their names.
+    dialect: Option<Dialect>,
+    documents: HashMap<String, String>,
+    exit: bool,
+}
+
+impl Server {
+    pub fn new(config: Config, dialect: Option<Dialect>) -> Self {
+        Self {
+            config,
+            dialect,
+            ..Self::default()
+        }
+    }
+
+    /// Whether the client has asked the server to exit.
+    pub fn should_exit(&self) -> bool {
+        self.exit
+    }
+
+    /// Handle one message from the client, returning the messages to send back.
+    pub fn handle(&mut self, message: &Value) -> Vec<Value> {
+        let Some(method) = message["method"].as_str() else {
+            // A response to a request we never make
+            return vec![];
+        };
+        let params = &message["params"];
+
+        let Some(id) = message.get("id").cloned() else {
+            return self.notification(method, params);
+        };
+
+        let response: Response = match method {
+            "initialize" => Ok(Self::initialize()),
+            "shutdown" => Ok(Value::Null),
+            "textDocument/formatting" => Ok(self.formatting(params, None)),
+            "textDocument/rangeFormatting" => Ok(self.formatting(params, Some(&params["range"]))),
+            "textDocument/definition" => Ok(self.definition(params)),
+            "textDocument/references" => Ok(self.references(params)),
+            "textDocument/rename" => self.rename(params),
+            "textDocument/completion" => Ok(self.completion(params)),
+            "textDocument/hover" => Ok(self.hover(params)),
+            "textDocument/documentSymbol" => Ok(self.outline(params, document_symbols)),
+            "textDocument/foldingRange" => Ok(self.outline(params, folding_ranges)),
+            _ => Err((METHOD_NOT_FOUND, format!("unsupported method: {method}"))),
+        };
+
+        vec![match response {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": code, "message": message },
+            }),
+        }]
+    }
+
+    fn notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+
+        match method {
+            "exit" => {
+                self.exit = true;
+                vec![]
+            }
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default();
+
+                self.documents.insert(uri.to_string(), text.to_string());
+                vec![self.publish_diagnostics(uri)]
+            }
+            "textDocument/didChange" => {
+                // Only full document sync is advertised, so the last change is the whole text
+                let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                else {
+                    return vec![];
+                };
+
+                self.documents.insert(uri.to_string(), text.to_string());
+                vec![self.publish_diagnostics(uri)]
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(uri);
+                vec![self.publish_diagnostics(uri)]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn initialize() -> Value {
+        json!({
+            "capabilities": {
+                "textDocumentSync": 1,
+                "documentFormattingProvider": true,
+                "documentRangeFormattingProvider": true,
+                "definitionProvider": true,
+                "referencesProvider": true,
+                "renameProvider": true,
+                "completionProvider": {},
+                "hoverProvider": true,
+                "documentSymbolProvider": true,
+                "foldingRangeProvider": true,
+            },
+            "serverInfo": {
+                "name": env!("CARGO_PKG_NAME"),
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+        })
+    }
+
+    fn source(&self, uri: &str) -> Option<Source> {
+        self.documents
+            .get(uri)
+            .map(|text| Source::new(uri_to_path(uri), text.clone()))
+    }
+
+    /// The document along with the files it sources, preferring open documents over the disk.
+    fn sources(&self, uri: &str) -> Option<Sources> {
+        let root = self.source(uri)?;
+
+        Some(Sources::load_with(root, |path| {
+            match self.documents.get(&path_to_uri(path)) {
+                Some(text) => Ok(Source::new(Some(path.to_path_buf()), text.clone())),
+                None => Source::read(path),
+            }
+        }))
+    }
+
+    /// Every occurrence of the variable under the cursor, as `(uri, range, is_definition)`.
+    fn variable_occurrences(&self, params: &Value) -> Vec<(String, Value, bool)> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some(sources) = self.sources(uri) else {
+            return vec![];
+        };
+
+        let (statements, _) = sources.statements();
+        let occurrences = occurrences(&statements);
+
+        let root = sources.root();
+        let position = &params["position"];
+        let offset = byte_offset(
+            &root.text,
+            usize::try_from(position["line"].as_u64().unwrap_or_default()).unwrap_or_default(),
+            usize::try_from(position["character"].as_u64().unwrap_or_default()).unwrap_or_default(),
+        );
+
+        let Some(target) = occurrences.iter().find(|occurrence| {
+            ptr::eq(occurrence.source, root)
+                && root
+                    .offset(occurrence.slice)
+                    .is_some_and(|start| (start..=start + occurrence.slice.len()).contains(&offset))
+        }) else {
+            return vec![];
+        };
+
+        occurrences
+            .iter()
+            .filter(|occurrence| occurrence.name == target.name)
+            .map(|occurrence| {
+                let occurrence_uri = match &occurrence.source.path {
+                    Some(path) if !ptr::eq(occurrence.source, root) => path_to_uri(path),
+                    _ => uri.to_string(),
+                };
+
+                (
+                    occurrence_uri,
+                    lsp_range(occurrence.source, occurrence.slice),
+                    occurrence.is_definition,
+                )
+            })
+            .collect()
+    }
+
+    fn definition(&self, params: &Value) -> Value {
+        self.variable_occurrences(params)
+            .into_iter()
+            .filter(|(_, _, is_definition)| *is_definition)
+            .map(|(uri, range, _)| json!({ "uri": uri, "range": range }))
+            .collect()
+    }
+
+    fn references(&self, params: &Value) -> Value {
+        let include_declaration = params["context"]["includeDeclaration"]
+            .as_bool()
+            .unwrap_or(true);
+
+        self.variable_occurrences(params)
+            .into_iter()
+            .filter(|(_, _, is_definition)| include_declaration || !*is_definition)
+            .map(|(uri, range, _)| json!({ "uri": uri, "range": range }))
+            .collect()
+    }
+
+    fn rename(&self, params: &Value) -> Response {
+        let new_name = params["newName"].as_str().unwrap_or_default();
+        let new_name = new_name.strip_prefix('$').unwrap_or(new_name);
+
+        if new_name.is_empty() || !new_name.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err((INVALID_PARAMS, format!("invalid variable name: {new_name}")));
+        }
+
+        let mut changes: HashMap<String, Vec<Value>> = HashMap::new();
+
+        for (uri, range, _) in self.variable_occurrences(params) {
+            changes
+                .entry(uri)
+                .or_default()
+                .push(json!({ "range": range, "newText": format!("${new_name}") }));
+        }
+
+        if changes.is_empty() {
+            return Ok(Value::Null);
+        }
+
+        Ok(json!({ "changes": changes }))
+    }
+
+    /// The document text, the cursor line, and the text of that line before the cursor.
+    fn cursor(&self, params: &Value) -> Option<(&str, usize, &str)> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let text = self.documents.get(uri)?;
+
+        let position = &params["position"];
+        let line = usize::try_from(position["line"].as_u64()?).ok()?;
+        let character = usize::try_from(position["character"].as_u64()?).ok()?;
+
+        let before = &text[byte_offset(text, line, 0)..byte_offset(text, line, character)];
+
+        Some((text, line, before))
+    }
+
+    fn schema(&self, params: &Value) -> &'static Schema {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+
+        self.dialect
+            .or_else(|| uri_to_path(uri).and_then(|path| Dialect::detect(&path)))
+            .unwrap_or_default()
+            .schema()
+    }
+
+    fn completion(&self, params: &Value) -> Value {
+        self.cursor(params)
+            .map_or(Value::Null, |(text, line, before)| {
+                completions(self.schema(params), text, line, before)
+            })
+    }
+
+    fn hover(&self, params: &Value) -> Value {
+        self.cursor(params)
+            .map_or(Value::Null, |(text, line, before)| {
+                hover(self.schema(params), text, line, before.len())
+            })
+    }
+
+    /// Apply `outline` to the document, or `null` when it doesn't parse.
+    fn outline(&self, params: &Value, outline: fn(&Source) -> Option<Value>) -> Value {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+
+        self.source(uri)
+            .and_then(|source| outline(&source))
+            .unwrap_or_default()
+    }
+
+    fn diagnostics(source: &Source) -> Vec<Diagnostic> {
+        match source.lines() {
+            Ok(_) => vec![],
+            Err(_) => source.parse_errors(),
+        }
+    }
+
+    fn publish_diagnostics(&self, uri: &str) -> Value {
+        let diagnostics = self.source(uri).map_or_else(Vec::new, |source| {
+            Self::diagnostics(&source)
+                .iter()
+                .map(|diagnostic| to_lsp_diagnostic(&source.text, diagnostic))
+                .collect()
+        });
+
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        })
+    }
+
+    /// The edits formatting the document, limited to those touching `range` when given.
+    fn formatting(&self, params: &Value, range: Option<&Value>) -> Value {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some(text) = self.documents.get(uri) else {
+            return Value::Null;
+        };
+
+        let mut config = self.config;
+        let options = &params["options"];
+
+        match options["insertSpaces"].as_bool() {
+            Some(true) => {
+                config.indent_mode = IndentMode::Spaces;
+                config.indent_width = options["tabSize"]
+                    .as_u64()
+                    .and_then(|size| u8::try_from(size).ok())
+                    .unwrap_or(config.indent_width);
+            }
+            Some(false) => {
+                config.indent_mode = IndentMode::Tabs;
+                config.indent_width = 1;
+            }
+            None => {}
+        }
+
+        // Lines that don't parse are kept as they are, and a formatter bug must not take the
+        // whole server down with it
+        let Ok(Ok((formatted, _))) =
+            panic::catch_unwind(AssertUnwindSafe(|| format_file_recovering(text, config)))
+        else {
+            return Value::Null;
+        };
+
+        let old = split_lines(text);
+        let new = split_lines(&formatted);
+
+        let line = |position: &Value| {
+            usize::try_from(position["line"].as_u64().unwrap_or_default()).unwrap_or(usize::MAX)
+        };
+        let lines = range.map(|range| (line(&range["start"]), line(&range["end"])));
+
+        diff(&old, &new)
+            .iter()
+            .filter(|hunk| {
+                lines.is_none_or(|(start, end)| {
+                    hunk.start <= end && hunk.end.max(hunk.start + 1) > start
+                })
+            })
+            .map(|hunk| text_edit(&old, hunk))
+            .collect()
+    }
+}
+
+/// Serve a client until it sends `exit` or closes its end of the connection.
+pub fn run(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+    config: Config,
+    dialect: Option<Dialect>,
+) -> io::Result<()> {
+    let mut server = Server::new(config, dialect);
+
+    while let Some(message) = read_message(reader)? {
+        for reply in server.handle(&message) {
+            write_message(writer, &reply)?;
+        }
+
+        if server.should_exit() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn to_lsp_diagnostic(text: &str, diagnostic: &Diagnostic) -> Value {
+    let location = &diagnostic.location;
+
+    json!({
+        "range": {
+            "start": lsp_position(text, location.start),
+            "end": lsp_position(text, location.end),
+        },
+        "severity": match diagnostic.severity {
+            Severity::Error => 1,
+            Severity::Warning => 2,
+            Severity::Note => 3,
+        },
+        "code": diagnostic.code,
+        "source": env!("CARGO_PKG_NAME"),
+        "message": diagnostic.message,
+    })
+}
+
+pub fn path_to_uri(path: &Path) -> String {
+    let encoded = path
+        .to_string_lossy()
+        .bytes()
+        .map(|byte| {
+            if byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte) {
+                char::from(byte).to_string()
+            } else {
+                format!("%{byte:02X}")
+            }
+        })
+        .collect::<String>();
+
+    format!("file://{encoded}")
+}
+
+pub fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let path = uri.strip_prefix("file://")?;
+
+    let mut bytes = vec![];
+    let mut rest = path.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        let decoded = (byte == b'%')
+            .then(|| tail.get(..2))
+            .flatten()
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        if let Some(decoded) = decoded {
+            bytes.push(decoded);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+
+    String::from_utf8(bytes).ok().map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    /// Send `requests` to a server as a client would, and collect everything it writes back.
+    fn session(requests: &[Value]) -> Vec<Value> {
+        let mut input = vec![];
+        for request in requests {
+            write_message(&mut input, request).unwrap();
+        }
+
+        let mut output = vec![];
+        run(
+            &mut Cursor::new(input),
+            &mut output,
+            Config::default(),
+            None,
+        )
+        .unwrap();
+
+        let mut output = Cursor::new(output);
+        let mut replies = vec![];
+        while let Some(reply) = read_message(&mut output).unwrap() {
+            replies.push(reply);
+        }
+
+        replies
+    }
+
+    fn open(uri: &str, text: &str) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {
+                "textDocument": { "uri": uri, "languageId": "hyprlang", "version": 1, "text": text },
+            },
+        })
+    }
+
+    #[test]
+    fn test_formatting() {
+        let replies = session(&[
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+            open("file:///hypr.conf", "a=1\nbbb=2\n\n\n\nc=3\n"),
+            json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "method": "textDocument/formatting",
+                "params": {
+                    "textDocument": { "uri": "file:///hypr.conf" },
+                    "options": { "tabSize": 4, "insertSpaces": true },
+                },
+            }),
+            json!({
+                "jsonrpc": "2.0",
+                "id": 3,
+                "method": "textDocument/rangeFormatting",
+                "params": {
+                    "textDocument": { "uri": "file:///hypr.conf" },
+                    "range": {
+                        "start": { "line": 5, "character": 0 },
+                        "end": { "line": 5, "character": 5 },
+                    },
+                    "options": { "tabSize": 4, "insertSpaces": true },
+                },
+            }),
+            json!({ "jsonrpc": "2.0", "id": 4, "method": "shutdown" }),
+            json!({ "jsonrpc": "2.0", "method": "exit" }),
+        ]);
+
+        assert_eq!(replies.len(), 5);
+        assert_eq!(
+            replies[0]["result"]["capabilities"]["documentFormattingProvider"],
+            true
+        );
+        assert_eq!(replies[1]["params"]["diagnostics"], json!([]));
+
+        let edits = replies[2]["result"].as_array().unwrap();
+        assert_eq!(
+            edits
+                .iter()
+                .map(|edit| &edit["newText"])
+                .collect::<Vec<_>>(),
+            ["a   = 1\nbbb = 2\n", "c = 3\n"]
+        );
+        assert_eq!(edits[0]["range"]["end"]["line"], 3);
+
+        let edits = replies[3]["result"].as_array().unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0]["range"]["start"]["line"], 5);
+        assert_eq!(edits[0]["newText"], "c = 3\n");
+
+        assert_eq!(replies[4]["id"], 4);
+    }
+
+    #[test]
+    fn test_parse_error_diagnostics() {
+        let replies = session(&[
+            open(
+                "file:///hypr.conf",
+                "general {\n  gaps_in = 5\n  =oops\n}\nbind = ]\na=1\nbbb=2\n",
+            ),
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "unknown/method" }),
+            json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "method": "textDocument/formatting",
+                "params": {
+                    "textDocument": { "uri": "file:///hypr.conf" },
+                    "options": { "tabSize": 4, "insertSpaces": true },
+                },
+            }),
+        ]);
+
+        let diagnostics = replies[0]["params"]["diagnostics"].as_array().unwrap();
+        assert_eq!(diagnostics.len(), 2);
+        assert!(
+            diagnostics
+                .iter()
+                .all(|diagnostic| diagnostic["code"] == "parse-error")
+        );
+        let mut lines = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic["range"]["start"]["line"].as_u64().unwrap())
+            .collect::<Vec<_>>();
+        lines.sort_unstable();
+        assert_eq!(lines, [2, 4]);
+
+        assert_eq!(replies[1]["error"]["code"], METHOD_NOT_FOUND);
+
+        // The invalid lines are kept as they are, and the rest is formatted
+        let edits = replies[2]["result"].as_array().unwrap();
+        assert_eq!(
+            edits
+                .iter()
+                .map(|edit| &edit["newText"])
+                .collect::<Vec<_>>(),
+            ["    gaps_in = 5\n", "\n", "a   = 1\nbbb = 2\n"]
+        );
+    }
+
+    #[test]
+    fn test_variable_navigation() {
+        let dir = std::env::temp_dir().join(format!("hyprlang-fmt-lsp-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("vars.conf"), "$terminal = kitty\n").unwrap();
+
+        let uri = path_to_uri(&dir.join("hyprland.conf"));
+        let vars_uri = path_to_uri(&dir.join("vars.conf"));
+        let at = |id: u64, method: &str, extra: Value| {
+            let mut request = json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "method": method,
+                "params": {
+                    "textDocument": { "uri": uri },
+                    "position": { "line": 1, "character": 25 },
+                },
+            });
+            if let (Some(params), Some(extra)) =
+                (request["params"].as_object_mut(), extra.as_object())
+            {
+                params.extend(extra.clone());
+            }
+            request
+        };
+
+        let replies = session(&[
+            open(
+                &uri,
+                "source = ./vars.conf\nbind = SUPER, Q, exec, $terminal\n",
+            ),
+            at(1, "textDocument/definition", json!({})),
+            at(
+                2,
+                "textDocument/references",
+                json!({ "context": { "includeDeclaration": false } }),
+            ),
+            at(3, "textDocument/rename", json!({ "newName": "$term" })),
+        ]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            replies[1]["result"],
+            json!([{
+                "uri": vars_uri,
+                "range": {
+                    "start": { "line": 0, "character": 0 },
+                    "end": { "line": 0, "character": 9 },
+                },
+            }])
+        );
+
+        let references = replies[2]["result"].as_array().unwrap();
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0]["uri"], uri);
+        assert_eq!(
+            references[0]["range"]["start"],
+            json!({ "line": 1, "character": 23 })
+        );
+
+        let changes = &replies[3]["result"]["changes"];
+        assert_eq!(changes[&uri][0]["newText"], "$term");
+        assert_eq!(changes[&vars_uri][0]["newText"], "$term");
+    }
+
+    #[test]
+    fn test_dialect_completion() {
+        let complete = |uri: &str| {
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "textDocument/completion",
+                "params": {
+                    "textDocument": { "uri": uri },
+                    "position": { "line": 1, "character": 2 },
+                },
+            })
+        };
+
+        let replies = session(&[
+            open("file:///hypridle.conf", "listener {\n  \n}\n"),
+            complete("file:///hypridle.conf"),
+            open("file:///hyprland.conf", "listener {\n  \n}\n"),
+            complete("file:///hyprland.conf"),
+        ]);
+
+        let labels = |reply: &Value| {
+            reply["result"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|item| item["label"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert!(labels(&replies[1]).contains(&"on-timeout".to_string()));
+        assert_eq!(labels(&replies[3]), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_uri_to_path() {
+        assert_eq!(
+            uri_to_path("file:///home/me/hypr%20conf/hyprland.conf"),
+            Some(PathBuf::from("/home/me/hypr conf/hyprland.conf"))
+        );
+        assert_eq!(uri_to_path("untitled:1"), None);
+    }
+}
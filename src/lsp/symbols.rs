@@ -0,0 +1,181 @@
+use serde_json::{Value, json};
+
+use crate::ast::{self, Comment, Item};
+use crate::parsed::line::Line;
+use crate::sources::Source;
+
+use super::edits::{lsp_range, lsp_span};
+
+/// REF
+/// - <https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#symbolKind>
+const SYMBOL_KIND_MODULE: u8 = 2;
+const SYMBOL_KIND_NAMESPACE: u8 = 3;
+const SYMBOL_KIND_VARIABLE: u8 = 13;
+
+/// The title of a section banner comment such as `### MONITORS ###`.
+fn banner_title<'a>(source: &'a Source, comment: &Comment<'a>) -> Option<&'a str> {
+    let hashes = source.text[comment.span.bytes.start..comment.text.span.bytes.start].trim_end();
+    let text = comment.text.text;
+    let title = text.trim_end_matches(['#', ' ']).trim();
+
+    (hashes.len() >= 2 && text.ends_with('#') && !title.is_empty()).then_some(title)
+}
+
+/// The symbols of `items` and the categories nested in them.
+fn symbols(source: &Source, items: &[Item]) -> Vec<Value> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Category(category) => {
+                // Up to the closing brace, without the comment after it
+                let end = category
+                    .comment
+                    .as_ref()
+                    .map_or(category.span.bytes.end, |comment| comment.span.bytes.start);
+                let text = source.text[category.span.bytes.start..end].trim_end();
+
+                Some(json!({
+                    "name": category.name.text,
+                    "kind": SYMBOL_KIND_NAMESPACE,
+                    "range": lsp_range(source, text),
+                    "selectionRange": lsp_range(source, category.name.text),
+                    "children": symbols(source, &category.items),
+                }))
+            }
+            Item::Assignment(assignment) => {
+                // With its leading `$`
+                let name = &source.text
+                    [assignment.name.span.bytes.start - 1..assignment.name.span.bytes.end];
+                let value = assignment.value.as_ref().map_or(name, |value| value.text);
+
+                Some(json!({
+                    "name": name,
+                    "detail": assignment.value.as_ref().map_or("", |value| value.text),
+                    "kind": SYMBOL_KIND_VARIABLE,
+                    "range": lsp_span(source, name, value),
+                    "selectionRange": lsp_range(source, name),
+                }))
+            }
+            Item::Comment(comment) => {
+                let title = banner_title(source, comment)?;
+
+                Some(json!({
+                    "name": title,
+                    "kind": SYMBOL_KIND_MODULE,
+                    "range": lsp_range(source, &source.text[comment.span.bytes.clone()]),
+                    "selectionRange": lsp_range(source, title),
+                }))
+            }
+            Item::Keyword(_) | Item::Directive(_) => None,
+        })
+        .collect()
+}
+
+/// Nested categories, variables and section banners, as LSP `DocumentSymbol`s, or `None` when
+/// `source` doesn't parse.
+pub fn document_symbols(source: &Source) -> Option<Value> {
+    let file = ast::parse(&source.text).ok()?;
+
+    Some(symbols(source, &file.items).into())
+}
+
+/// Folding ranges for categories and blocks of consecutive comment lines, or `None` when
+/// `source` doesn't parse.
+pub fn folding_ranges(source: &Source) -> Option<Value> {
+    let lines = source.lines().ok()?;
+
+    // LSP lines are 0-based
+    let line_of = |slice: &str| source.location(slice).start.line - 1;
+
+    let mut ranges = vec![];
+    let mut starts = vec![];
+    let mut comments: Option<(usize, usize)> = None;
+
+    for line in lines.iter() {
+        let comment_line = match line {
+            Line::Comment(info) => Some(line_of(info.lhs)),
+            _ => None,
+        };
+
+        match (comments, comment_line) {
+            (Some((start, end)), Some(line)) if line == end + 1 => {
+                comments = Some((start, line));
+            }
+            _ => {
+                if let Some((start, end)) = comments.take()
+                    && end > start
+                {
+                    ranges.push(json!({ "startLine": start, "endLine": end, "kind": "comment" }));
+                }
+
+                comments = comment_line.map(|line| (line, line));
+            }
+        }
+
+        match line {
+            Line::CategoryStart(info) => starts.push(line_of(info.lhs)),
+            Line::CategoryEnd(info) => {
+                // Keep the closing brace visible
+                if let Some(start) = starts.pop()
+                    && let Some(end) = line_of(info.lhs).checked_sub(1)
+                    && end > start
+                {
+                    ranges.push(json!({ "startLine": start, "endLine": end }));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((start, end)) = comments
+        && end > start
+    {
+        ranges.push(json!({ "startLine": start, "endLine": end, "kind": "comment" }));
+    }
+
+    Some(ranges.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbols_and_folding() {
+        let source = Source::new(
+            None,
+            [
+                "### VARIABLES ###",
+                "$mod = SUPER",
+                "# one",
+                "# two",
+                "general {",
+                "    gaps_in = 5",
+                "    snap {",
+                "        enabled = true",
+                "    }",
+                "}",
+                "",
+            ]
+            .join("\n"),
+        );
+        let symbols = document_symbols(&source).unwrap();
+        assert_eq!(symbols[0]["name"], "VARIABLES");
+        assert_eq!(symbols[0]["kind"], SYMBOL_KIND_MODULE);
+        assert_eq!(symbols[1]["name"], "$mod");
+        assert_eq!(symbols[1]["detail"], "SUPER");
+        assert_eq!(symbols[2]["name"], "general");
+        assert_eq!(symbols[2]["range"]["end"]["line"], 9);
+        assert_eq!(symbols[2]["children"][0]["name"], "snap");
+        assert_eq!(symbols[2]["children"][0]["range"]["start"]["line"], 6);
+
+        assert_eq!(
+            folding_ranges(&source).unwrap(),
+            json!([
+                { "startLine": 2, "endLine": 3, "kind": "comment" },
+                { "startLine": 6, "endLine": 7 },
+                { "startLine": 4, "endLine": 8 },
+            ])
+        );
+    }
+}
@@ -7,15 +7,31 @@
 #![warn(clippy::suspicious)]
 
 mod cli;
-mod config;
-mod grammar;
-mod parse;
-mod parsed;
 
-fn main() {
-    let config = cli::get_config();
+use std::process::ExitCode;
 
-    let file = cli::get_file();
+use cli::args::Command;
 
-    cli::run(config, &file);
+fn main() -> ExitCode {
+    let mut args = cli::get_args();
+
+    match args.command.take() {
+        Some(Command::Lint(lint)) => cli::lint(lint),
+        Some(Command::Binds(binds)) => cli::binds(&binds),
+        Some(Command::Lsp(lsp)) => cli::lsp(lsp),
+        Some(Command::Migrate(migrate)) => cli::migrate(migrate),
+        Some(Command::Dump(dump)) => cli::dump(&dump),
+        Some(Command::Semdiff(semdiff)) => cli::semdiff(&semdiff),
+        None => {
+            let file = cli::get_file();
+
+            if args.warn_variables {
+                cli::warn_variables(&file);
+            }
+
+            let (verify, on_error) = (args.verify, args.on_error);
+
+            cli::run(args.into(), verify, on_error, &file)
+        }
+    }
 }
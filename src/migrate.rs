@@ -0,0 +1,336 @@
+use std::ops::Range;
+
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::ParseError;
+use crate::parsed::line::Line;
+use crate::schema::{Dialect, path};
+use crate::sources::Source;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    /// The option moved to another path, with the same values.
+    Rename(&'static str),
+    /// The option is gone, and what to do instead.
+    Remove(&'static str),
+}
+
+/// A change to an option in a Hyprland release.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Migration {
+    /// The release that made the change.
+    pub version: &'static str,
+    pub path: &'static str,
+    pub change: Change,
+}
+
+const fn rename(version: &'static str, path: &'static str, to: &'static str) -> Migration {
+    Migration {
+        version,
+        path,
+        change: Change::Rename(to),
+    }
+}
+
+const fn remove(version: &'static str, path: &'static str, note: &'static str) -> Migration {
+    Migration {
+        version,
+        path,
+        change: Change::Remove(note),
+    }
+}
+
+/// REF
+/// - <https://github.com/hyprwm/Hyprland/releases>
+#[rustfmt::skip]
+pub const MIGRATIONS: &[Migration] = &[
+    rename("0.33.0", "general:col.group_border", "group:col.border_inactive"),
+    rename("0.33.0", "general:col.group_border_active", "group:col.border_active"),
+    rename("0.33.0", "misc:group_insert_after_current", "group:insert_after_current"),
+    rename("0.33.0", "misc:group_focus_removed_window", "group:focus_removed_window"),
+    rename("0.33.0", "misc:groupbar_titles_font_size", "group:groupbar:font_size"),
+    rename("0.33.0", "misc:groupbar_gradients", "group:groupbar:gradients"),
+    rename("0.33.0", "misc:render_titles_in_groupbar", "group:groupbar:render_titles"),
+    rename("0.33.0", "misc:groupbar_text_color", "group:groupbar:text_color"),
+    rename("0.37.0", "general:no_cursor_warps", "cursor:no_warps"),
+    rename("0.37.0", "general:cursor_inactive_timeout", "cursor:inactive_timeout"),
+    rename("0.37.0", "misc:hide_cursor_on_touch", "cursor:hide_on_touch"),
+    rename("0.37.0", "misc:hide_cursor_on_key_press", "cursor:hide_on_key_press"),
+    rename("0.37.0", "misc:cursor_zoom_factor", "cursor:zoom_factor"),
+    rename("0.37.0", "misc:cursor_zoom_rigid", "cursor:zoom_rigid"),
+    remove("0.41.0", "master:new_is_master", "use `master:new_status = master` or `slave` instead"),
+    remove("0.42.0", "misc:no_direct_scanout", "use `render:direct_scanout`, which has the opposite meaning"),
+    remove("0.45.0", "master:no_gaps_when_only", "use workspace rules for \"smart gaps\" instead"),
+    remove("0.45.0", "dwindle:no_gaps_when_only", "use workspace rules for \"smart gaps\" instead"),
+    rename("0.45.0", "decoration:drop_shadow", "decoration:shadow:enabled"),
+    rename("0.45.0", "decoration:shadow_range", "decoration:shadow:range"),
+    rename("0.45.0", "decoration:shadow_render_power", "decoration:shadow:render_power"),
+    rename("0.45.0", "decoration:shadow_ignore_window", "decoration:shadow:ignore_window"),
+    rename("0.45.0", "decoration:shadow_offset", "decoration:shadow:offset"),
+    rename("0.45.0", "decoration:shadow_scale", "decoration:shadow:scale"),
+    rename("0.45.0", "decoration:col.shadow", "decoration:shadow:color"),
+    rename("0.45.0", "decoration:col.shadow_inactive", "decoration:shadow:color_inactive"),
+    remove("0.51.0", "gestures:workspace_swipe", "use `gesture = 3, horizontal, workspace` instead"),
+];
+
+/// `version` as numbers, so that `0.9.0` sorts before `0.10.0`.
+fn version_key(version: &str) -> Vec<u64> {
+    version
+        .trim_start_matches('v')
+        .split('.')
+        .map(|part| part.parse().unwrap_or_default())
+        .collect()
+}
+
+/// The byte range of the whole line containing `offset`, including its newline.
+fn line_range(text: &str, offset: usize) -> Range<usize> {
+    let start = text[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+    let end = text[offset..]
+        .find('\n')
+        .map_or(text.len(), |idx| offset + idx + 1);
+
+    start..end
+}
+
+/// A category that is open while migrating.
+struct Block {
+    /// Where the line that opens it starts.
+    start: usize,
+    /// How many lines are in it, not counting blank lines and the contents of nested categories.
+    lines: usize,
+    /// How many of those moved out of it, or are nested categories that were removed.
+    moved: usize,
+}
+
+/// `text` with every range in `edits` replaced.
+fn apply(text: &str, mut edits: Vec<(Range<usize>, String)>) -> String {
+    let mut text = text.to_string();
+
+    edits.sort_by_key(|(range, _)| range.start);
+    for (range, replacement) in edits.into_iter().rev() {
+        text.replace_range(range, &replacement);
+    }
+
+    text
+}
+
+/// Close the innermost category, which ends at `end`, and remove it when everything in it moved
+/// out.
+fn close(blocks: &mut Vec<Block>, end: usize, edits: &mut Vec<(Range<usize>, String)>) {
+    let Some(block) = blocks.pop() else {
+        return;
+    };
+    if block.moved == 0 || block.moved < block.lines {
+        return;
+    }
+
+    edits.retain(|(range, _)| range.start <= block.start || range.end > end);
+    edits.push((block.start..end, String::new()));
+
+    if let Some(parent) = blocks.last_mut() {
+        parent.moved += 1;
+    }
+}
+
+/// Apply the migrations from releases after `since` (or all of them) to `source`, a config for
+/// `dialect`. The migrations are for Hyprland, so configs for other programs are left as they are.
+///
+/// Options renamed within the category they are set in are renamed in place. Options that move
+/// to another top level category are taken out of theirs and set with their full path after it,
+/// and categories left empty by that are removed. Removed options are left as they are. The
+/// result still needs formatting.
+///
+/// Every change made and every removed option found is reported.
+pub fn migrate(
+    source: &Source,
+    dialect: Dialect,
+    since: Option<&str>,
+) -> Result<(String, Vec<Diagnostic>), ParseError> {
+    let text = &source.text;
+    if dialect != Dialect::Hyprland {
+        return Ok((text.clone(), vec![]));
+    }
+
+    let since = since.map(version_key);
+    let migrations = MIGRATIONS
+        .iter()
+        .filter(|migration| {
+            since
+                .as_ref()
+                .is_none_or(|since| version_key(migration.version) > *since)
+        })
+        .collect::<Vec<_>>();
+
+    let mut edits: Vec<(Range<usize>, String)> = vec![];
+    let mut diagnostics = vec![];
+
+    let mut categories = vec![];
+    let mut blocks: Vec<Block> = vec![];
+    // Lines moving out of the current top level category, to insert after it
+    let mut moved = vec![];
+
+    for line in source.lines()?.iter() {
+        if !matches!(line, Line::Newline | Line::CategoryEnd(_))
+            && let Some(block) = blocks.last_mut()
+        {
+            block.lines += 1;
+        }
+
+        let info = match line {
+            Line::CategoryStart(info) => {
+                categories.push(info.lhs);
+                blocks.push(Block {
+                    start: line_range(text, source.offset(info.lhs).unwrap_or_default()).start,
+                    lines: 0,
+                    moved: 0,
+                });
+                continue;
+            }
+            Line::CategoryEnd(info) => {
+                categories.pop();
+                let end = line_range(text, source.offset(info.lhs).unwrap_or_default()).end;
+
+                close(&mut blocks, end, &mut edits);
+
+                if categories.is_empty() && !moved.is_empty() {
+                    let newline = if text[..end].ends_with('\n') {
+                        ""
+                    } else {
+                        "\n"
+                    };
+
+                    edits.push((end..end, format!("{newline}{}", moved.join(""))));
+                    moved.clear();
+                }
+                continue;
+            }
+            Line::Sectioned(info) if !info.lhs.starts_with('$') && info.bind.is_none() => info,
+            _ => continue,
+        };
+
+        let full = path(&categories, info.lhs);
+        let Some(migration) = migrations.iter().find(|migration| migration.path == full) else {
+            continue;
+        };
+
+        let location = source.location(info.lhs);
+        let start = source.offset(info.lhs).unwrap_or_default();
+        let lhs = start..start + info.lhs.len();
+
+        match migration.change {
+            Change::Rename(to) => {
+                let category = categories.join(":");
+                let relative = match category.as_str() {
+                    "" => Some(to),
+                    _ => to
+                        .strip_prefix(&category)
+                        .and_then(|to| to.strip_prefix(':')),
+                };
+
+                if let Some(relative) = relative {
+                    edits.push((lhs, relative.to_string()));
+                } else {
+                    let line = line_range(text, start);
+                    let rest = text[lhs.end..line.end].trim_end_matches('\n');
+
+                    moved.push(format!("{to}{rest}\n"));
+                    edits.push((line, String::new()));
+
+                    if let Some(block) = blocks.last_mut() {
+                        block.moved += 1;
+                    }
+                }
+
+                diagnostics.push(Diagnostic::new(
+                    "migrated",
+                    Severity::Note,
+                    location,
+                    format!("renamed {full} to {to} (Hyprland {})", migration.version),
+                ));
+            }
+            Change::Remove(note) => diagnostics.push(Diagnostic::new(
+                "removed-option",
+                Severity::Warning,
+                location,
+                format!(
+                    "{full} was removed in Hyprland {}, {note}",
+                    migration.version
+                ),
+            )),
+        }
+    }
+
+    Ok((apply(text, edits), diagnostics))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate() {
+        let source = Source::new(
+            None,
+            [
+                "general {",
+                "    gaps_in = 5",
+                "    no_cursor_warps = true # keep it still",
+                "}",
+                "misc {",
+                "    cursor_zoom_factor = 2",
+                "",
+                "}",
+                "decoration {",
+                "    drop_shadow = false",
+                "}",
+                "decoration:col.shadow = rgba(1a1a1aee)",
+                "master {",
+                "    new_is_master = true",
+                "}",
+                "",
+            ]
+            .join("\n"),
+        );
+
+        let (migrated, diagnostics) = migrate(&source, Dialect::Hyprland, None).unwrap();
+
+        assert_eq!(
+            migrated,
+            [
+                "general {",
+                "    gaps_in = 5",
+                "}",
+                "cursor:no_warps = true # keep it still",
+                "cursor:zoom_factor = 2",
+                "decoration {",
+                "    shadow:enabled = false",
+                "}",
+                "decoration:shadow:color = rgba(1a1a1aee)",
+                "master {",
+                "    new_is_master = true",
+                "}",
+                "",
+            ]
+            .join("\n")
+        );
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.code)
+                .collect::<Vec<_>>(),
+            [
+                "migrated",
+                "migrated",
+                "migrated",
+                "migrated",
+                "removed-option"
+            ]
+        );
+
+        let (_, diagnostics) = migrate(&source, Dialect::Hyprland, Some("0.41.0")).unwrap();
+        assert_eq!(diagnostics.len(), 2);
+
+        let (migrated, diagnostics) = migrate(&source, Dialect::Hyprlock, None).unwrap();
+        assert_eq!(migrated, source.text);
+        assert!(diagnostics.is_empty());
+    }
+}
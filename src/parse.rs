@@ -1,13 +1,33 @@
 use crate::grammar::{HyprlangParser, Rule};
-use pest::iterators::FlatPairs;
 use pest::Parser;
+use pest::error::{InputLocation, LineColLocation};
+use pest::iterators::FlatPairs;
 use std::fmt;
+use std::ops::Range;
 
 pub type ParseIterator<'a> = FlatPairs<'a, Rule>;
 
 #[derive(Debug)]
 pub enum ParseError {
-    InvalidInput(Box<dyn std::error::Error>),
+    InvalidInput(Box<pest::error::Error<Rule>>),
+}
+
+impl ParseError {
+    /// The 1-based (line, column) pair where parsing failed.
+    pub fn line_col(&self) -> (usize, usize) {
+        match self {
+            ParseError::InvalidInput(e) => match e.line_col {
+                LineColLocation::Pos(pos) | LineColLocation::Span(pos, _) => pos,
+            },
+        }
+    }
+
+    /// A single-line description of the error, without the source excerpt.
+    pub fn message(&self) -> String {
+        match self {
+            ParseError::InvalidInput(e) => e.variant.message().into_owned(),
+        }
+    }
 }
 
 impl fmt::Display for ParseError {
@@ -25,3 +45,100 @@ pub fn get_file_tokens_iterator(file: &str) -> Result<ParseIterator<'_>, ParseEr
 
     Ok(pair.into_inner().flatten())
 }
+
+/// A file parsed by [`recover`].
+#[derive(Debug)]
+pub struct Recovered {
+    /// The file with every invalid line commented out. Invalid lines are replaced by a comment of
+    /// the same length, so byte offsets and positions are the same as in the original file.
+    pub text: String,
+    /// The byte range of every invalid line (without its line break) and why the file didn't
+    /// parse, in line order.
+    pub invalid: Vec<(Range<usize>, ParseError)>,
+}
+
+/// The byte range of every line of `text`, without its line break.
+fn line_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut start = 0;
+
+    text.split('\n')
+        .map(|line| {
+            let range = start..start + line.strip_suffix('\r').unwrap_or(line).len();
+            start += line.len() + 1;
+
+            range
+        })
+        .collect()
+}
+
+/// The line of the innermost category of `text` that is never closed, if any.
+fn unclosed(text: &str, lines: &[Range<usize>]) -> Option<Range<usize>> {
+    let mut open = vec![];
+
+    for range in lines {
+        let line = text[range.clone()]
+            .split('#')
+            .next()
+            .unwrap_or_default()
+            .trim();
+
+        if line.ends_with('{') {
+            open.push(range.clone());
+        } else if line.starts_with('}') {
+            open.pop();
+        }
+    }
+
+    open.pop()
+}
+
+/// Parse `file`, commenting out invalid lines one at a time until the rest parses.
+///
+/// The line parsing fails on is the one taken as invalid. When parsing fails at the end of the
+/// file, that is the category that is never closed, and otherwise when the line is blank or
+/// already commented out, the closest line before it.
+pub fn recover(file: &str) -> Result<Recovered, ParseError> {
+    let lines = line_ranges(file);
+    let mut text = file.to_string();
+    let mut invalid: Vec<(Range<usize>, ParseError)> = vec![];
+
+    while let Err(e) = HyprlangParser::parse(Rule::file, &text) {
+        let is_candidate = |range: &Range<usize>| {
+            !text[range.clone()].trim_start_matches(' ').is_empty()
+                && invalid.iter().all(|(other, _)| other != range)
+        };
+
+        let at_end = match e.location {
+            InputLocation::Pos(pos) | InputLocation::Span((pos, _)) => {
+                text[pos..].trim().is_empty()
+            }
+        };
+        let (line, _) = match e.line_col {
+            LineColLocation::Pos(pos) | LineColLocation::Span(pos, _) => pos,
+        };
+
+        let range = at_end
+            .then(|| unclosed(&text, &lines))
+            .flatten()
+            .filter(is_candidate)
+            .or_else(|| {
+                lines[..line.min(lines.len())]
+                    .iter()
+                    .rev()
+                    .find(|range| is_candidate(range))
+                    .cloned()
+            });
+
+        let error = ParseError::InvalidInput(Box::new(e));
+        let Some(range) = range else {
+            return Err(error);
+        };
+
+        text.replace_range(range.clone(), &format!("#{}", " ".repeat(range.len() - 1)));
+        invalid.push((range, error));
+    }
+
+    invalid.sort_by_key(|(range, _)| range.start);
+
+    Ok(Recovered { text, invalid })
+}
@@ -0,0 +1,299 @@
+use std::collections::BTreeSet;
+
+use crate::config::ModsSeparator;
+
+/// Flags that may follow the `bind` keyword, e.g. `bindl` or `bindel`.
+///
+/// REF
+/// - <https://wiki.hyprland.org/Configuring/Binds/#bind-flags>
+const BIND_FLAGS: &str = "lrcgoentmisdp";
+
+/// Canonical modifier order, with the aliases Hyprland accepts for each.
+///
+/// REF
+/// - <https://wiki.hyprland.org/Configuring/Variables/#variable-types>
+const MODS: [&[&str]; 8] = [
+    &["SUPER", "WIN", "LOGO", "MOD4", "META"],
+    &["CTRL", "CONTROL"],
+    &["ALT", "MOD1"],
+    &["SHIFT"],
+    &["CAPS"],
+    &["MOD2"],
+    &["MOD3"],
+    &["MOD5"],
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bind<'a> {
+    pub flags: &'a str,
+    pub mods: Vec<&'a str>,
+    pub mods_str: &'a str,
+    pub key: &'a str,
+    pub description: Option<&'a str>,
+    pub dispatcher: &'a str,
+    pub args: Option<&'a str>,
+}
+
+impl<'a> Bind<'a> {
+    /// Build a bind from the keyword (`bind`, `bindl`, ...) and its right-hand side.
+    ///
+    /// Returns `None` when `keyword` is not a bind keyword.
+    pub fn parse(keyword: &'a str, rhs: &'a str) -> Option<Self> {
+        let flags = bind_flags(keyword)?;
+
+        let mut fields = rhs.split(',').map(str::trim);
+
+        let mods_str = fields.next().unwrap_or_default();
+        let key = fields.next().unwrap_or_default();
+
+        let description = if flags.contains('d') {
+            fields.next()
+        } else {
+            None
+        };
+
+        let dispatcher = fields.next().unwrap_or_default();
+
+        // Arguments may themselves contain commas (e.g. `exec, notify-send "a, b"`),
+        // so everything after the dispatcher is kept as-is.
+        let args = split_after_fields(rhs, if description.is_some() { 4 } else { 3 })
+            .map(str::trim)
+            .filter(|args| !args.is_empty());
+
+        Some(Self {
+            flags,
+            mods: split_mods(mods_str),
+            mods_str,
+            key,
+            description,
+            dispatcher,
+            args,
+        })
+    }
+
+    pub fn has_flag(&self, flag: char) -> bool {
+        self.flags.contains(flag)
+    }
+
+    /// The modifier field in canonical order and casing: variables first, in their original
+    /// order, then known modifiers by their canonical name, then anything unrecognized.
+    ///
+    /// Variables are always followed by a space, as a `_` would be read as part of their name.
+    pub fn normalized_mods(&self, separator: ModsSeparator) -> String {
+        let mut mods = canonical_parts(self.mods_str);
+
+        // Stable, so variables and unrecognized parts keep their relative order
+        mods.sort_by_key(|(rank, _)| *rank);
+
+        let mut normalized = String::new();
+        let mut after_variable = false;
+
+        for (rank, part) in mods {
+            if !normalized.is_empty() {
+                normalized.push_str(if after_variable {
+                    " "
+                } else {
+                    separator.into_str()
+                });
+            }
+
+            normalized.push_str(&part);
+            after_variable = rank == 0;
+        }
+
+        normalized
+    }
+}
+
+/// The positions in [`MODS`] of the modifiers an upper case word is made of, e.g. `SUPERSHIFT`,
+/// or `None` if it isn't made of modifiers only.
+fn split_glued(word: &str) -> Option<Vec<usize>> {
+    if word.is_empty() {
+        return Some(Vec::new());
+    }
+
+    MODS.iter().enumerate().find_map(|(rank, aliases)| {
+        aliases.iter().find_map(|alias| {
+            let mut ranks = split_glued(word.strip_prefix(alias)?)?;
+            ranks.insert(0, rank);
+            Some(ranks)
+        })
+    })
+}
+
+/// The parts of a modifier field in canonical casing, with aliases replaced by their canonical
+/// name and words such as `SUPERSHIFT` split into their modifiers, along with where each goes:
+/// variables first, then known modifiers, then anything unrecognized.
+fn canonical_parts(mods: &str) -> Vec<(usize, String)> {
+    split_mods(mods)
+        .into_iter()
+        .flat_map(|part| {
+            if part.starts_with('$') {
+                return vec![(0, part.to_string())];
+            }
+
+            let part = part.to_uppercase();
+
+            match split_glued(&part) {
+                Some(ranks) => ranks
+                    .into_iter()
+                    .map(|rank| (rank + 1, MODS[rank][0].to_string()))
+                    .collect(),
+                None => vec![(MODS.len() + 1, part)],
+            }
+        })
+        .collect()
+}
+
+/// The parts of a modifier field as they are written, in canonical casing and with aliases
+/// replaced by their canonical name. Unlike [`canonical_mods`], unrecognized parts are kept.
+pub fn mod_set(mods: &str) -> BTreeSet<String> {
+    canonical_parts(mods)
+        .into_iter()
+        .map(|(_, part)| part)
+        .collect()
+}
+
+/// The set of modifiers in a modifier field with variables already substituted, in canonical
+/// order, with aliases (e.g. `WIN`, `CONTROL`) replaced by their canonical name.
+///
+/// Like Hyprland, any separator is accepted and unrecognized words are ignored: `SUPER and SHIFT`
+/// is the same as `SUPERSHIFT`. Variables that could not be substituted are kept as-is.
+pub fn canonical_mods(mods: &str) -> Vec<String> {
+    let mut mods = canonical_parts(mods)
+        .into_iter()
+        .filter(|(rank, _)| *rank <= MODS.len())
+        .collect::<Vec<_>>();
+
+    mods.sort_by_key(|(rank, _)| *rank);
+    mods.dedup();
+
+    mods.into_iter().map(|(_, part)| part).collect()
+}
+
+/// Returns the flags of a bind keyword, or `None` if `keyword` is not one.
+pub fn bind_flags(keyword: &str) -> Option<&str> {
+    let flags = keyword.strip_prefix("bind")?;
+
+    flags
+        .chars()
+        .all(|flag| BIND_FLAGS.contains(flag))
+        .then_some(flags)
+}
+
+/// Split a modifier field such as `$mainMod SHIFT` or `super_shift` into its parts, on
+/// whitespace, `+` and `_`. Like in the grammar, a variable name is made of ASCII letters and
+/// digits only, so `$mainMod_SHIFT` is `$mainMod` followed by `SHIFT`.
+pub fn split_mods(mods: &str) -> Vec<&str> {
+    let is_separator = |c: char| c.is_whitespace() || c == '+' || c == '_';
+
+    let mut parts = Vec::new();
+    let mut rest = mods.trim_start_matches(is_separator);
+
+    while !rest.is_empty() {
+        let end = match rest.strip_prefix('$') {
+            Some(name) => {
+                1 + name
+                    .find(|c: char| !c.is_ascii_alphanumeric())
+                    .unwrap_or(name.len())
+            }
+            None => rest
+                .find(|c: char| is_separator(c) || c == '$')
+                .unwrap_or(rest.len()),
+        };
+
+        parts.push(&rest[..end]);
+        rest = rest[end..].trim_start_matches(is_separator);
+    }
+
+    parts
+}
+
+fn split_after_fields(rhs: &str, count: usize) -> Option<&str> {
+    rhs.splitn(count + 1, ',').nth(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bind() {
+        let bind = Bind::parse("bindl", "$mainMod SHIFT, Q, exec, notify-send \"a, b\"").unwrap();
+
+        assert_eq!(bind.flags, "l");
+        assert_eq!(bind.mods, ["$mainMod", "SHIFT"]);
+        assert_eq!(bind.key, "Q");
+        assert_eq!(bind.description, None);
+        assert_eq!(bind.dispatcher, "exec");
+        assert_eq!(bind.args, Some("notify-send \"a, b\""));
+    }
+
+    #[test]
+    fn test_parse_bind_with_description() {
+        let bind = Bind::parse("bindd", "super_shift, C, Close window, killactive,").unwrap();
+
+        assert_eq!(bind.mods, ["super", "shift"]);
+        assert_eq!(bind.description, Some("Close window"));
+        assert_eq!(bind.dispatcher, "killactive");
+        assert_eq!(bind.args, None);
+    }
+
+    #[test]
+    fn test_normalized_mods() {
+        let bind = Bind::parse("bind", "shift CONTROL $mainMod super, Q, exec, kitty").unwrap();
+
+        assert_eq!(
+            bind.normalized_mods(ModsSeparator::Space),
+            "$mainMod SUPER CTRL SHIFT"
+        );
+        assert_eq!(
+            bind.normalized_mods(ModsSeparator::Underscore),
+            "$mainMod SUPER_CTRL_SHIFT"
+        );
+
+        let bind = Bind::parse("bind", "control win, Q, exec, kitty").unwrap();
+        assert_eq!(bind.normalized_mods(ModsSeparator::Space), "SUPER CTRL");
+
+        let bind = Bind::parse("bind", "$mainMod_shiftsuper, Q, exec, kitty").unwrap();
+        assert_eq!(bind.mods, ["$mainMod", "shiftsuper"]);
+        assert_eq!(
+            bind.normalized_mods(ModsSeparator::Underscore),
+            "$mainMod SUPER_SHIFT"
+        );
+    }
+
+    #[test]
+    fn test_canonical_mods() {
+        assert_eq!(
+            canonical_mods("shift WIN_control"),
+            ["SUPER", "CTRL", "SHIFT"]
+        );
+        assert_eq!(canonical_mods("CTRL and SHIFT"), ["CTRL", "SHIFT"]);
+        assert_eq!(
+            canonical_mods("$hyper ALTSHIFT"),
+            ["$hyper", "ALT", "SHIFT"]
+        );
+        assert_eq!(canonical_mods("SHIFT super_shift"), ["SUPER", "SHIFT"]);
+        assert_eq!(canonical_mods(""), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_mod_set() {
+        assert_eq!(
+            mod_set("shift win_SUPER $mainMod FOO"),
+            BTreeSet::from(["$mainMod", "FOO", "SHIFT", "SUPER"].map(String::from))
+        );
+        assert_eq!(mod_set("SUPERSHIFT"), mod_set("SUPER SHIFT"));
+        assert_eq!(
+            mod_set("$mainMod_SHIFT"),
+            BTreeSet::from(["$mainMod", "SHIFT"].map(String::from))
+        );
+    }
+
+    #[test]
+    fn test_parse_not_a_bind() {
+        assert_eq!(Bind::parse("monitor", ",preferred,auto,auto"), None);
+        assert_eq!(Bind::parse("unbind", "SUPER, Q"), None);
+    }
+}
@@ -7,6 +7,10 @@ use crate::config::SpacingContext;
 use crate::parsed::line::{Line, LineInfo};
 
 use crate::config::Config;
+use crate::cst;
+use crate::expression::normalize_all;
+use crate::parse::{ParseError, recover};
+use crate::parsed::line::get_lines;
 
 use std::rc::Rc;
 
@@ -46,6 +50,23 @@ impl fmt::Display for TableError {
 
 type TableResult<T = ()> = Result<T, TableError>;
 
+#[derive(Debug)]
+pub enum FormatError {
+    Parse(ParseError),
+    Table(TableError),
+}
+
+impl Error for FormatError {}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FormatError::Parse(e) => e.fmt(f),
+            FormatError::Table(e) => e.fmt(f),
+        }
+    }
+}
+
 impl<'a> Table<'a> {
     fn new(config: Config, lines: &'a Rc<[Line<'_>]>) -> Self {
         let mut rows = vec![];
@@ -233,11 +254,66 @@ impl<'a> Table<'a> {
     }
 }
 
+/// Parse a whole file into a tree and format the lines of the tree.
+pub fn format_file(file: &str, config: Config) -> Result<String, FormatError> {
+    let tree = cst::parse(file).map_err(FormatError::Parse)?;
+
+    let lines = get_lines(&tree);
+
+    format_lines(&lines, config).map_err(FormatError::Table)
+}
+
+/// The comment invalid lines are replaced with while the rest of the file is formatted, followed
+/// by the index of the line.
+const INVALID_LINE: &str = "hyprlang-fmt: invalid line";
+
+/// Format `file` even if some of its lines don't parse: they are passed through as they are, and
+/// returned along with why they don't parse.
+pub fn format_file_recovering(
+    file: &str,
+    config: Config,
+) -> Result<(String, Vec<ParseError>), FormatError> {
+    let recovered = recover(file).map_err(FormatError::Parse)?;
+    if recovered.invalid.is_empty() {
+        return format_file(file, config).map(|formatted| (formatted, vec![]));
+    }
+
+    let mut text = file.to_string();
+    for (idx, (range, _)) in recovered.invalid.iter().enumerate().rev() {
+        text.replace_range(range.clone(), &format!("# {INVALID_LINE} {idx}"));
+    }
+
+    let formatted = format_file(&text, config)?
+        .split_inclusive('\n')
+        .map(|line| {
+            let invalid = line
+                .trim()
+                .trim_start_matches('#')
+                .trim_start()
+                .strip_prefix(INVALID_LINE)
+                .and_then(|idx| idx.trim().parse::<usize>().ok())
+                .and_then(|idx| recovered.invalid.get(idx));
+
+            match invalid {
+                Some((range, _)) => {
+                    let newline = if line.ends_with('\n') { "\n" } else { "" };
+                    format!("{}{newline}", &file[range.clone()])
+                }
+                None => line.to_string(),
+            }
+        })
+        .collect();
+
+    let errors = recovered.invalid.into_iter().map(|(_, e)| e).collect();
+
+    Ok((formatted, errors))
+}
+
 pub fn format_lines(lines: &Rc<[Line<'_>]>, config: Config) -> TableResult<String> {
     let mut table = Table::new(config, lines);
 
     update_mid_column(config, lines, &mut table)?;
-    update_rhs_column(lines, &mut table)?;
+    update_rhs_column(config, lines, &mut table)?;
     update_comment_column(config, lines, &mut table)?;
 
     Ok(table.format())
@@ -260,10 +336,23 @@ pub fn update_mid_column(config: Config, lines: &Rc<[Line]>, table: &mut Table)
     Ok(())
 }
 
-pub fn update_rhs_column(lines: &Rc<[Line]>, table: &mut Table) -> TableResult {
+pub fn update_rhs_column(config: Config, lines: &Rc<[Line]>, table: &mut Table) -> TableResult {
     for (pos, line) in lines.iter().enumerate() {
-        if let Some(rhs) = line.as_sectionable().and_then(|info| info.rhs) {
-            table.append_to_row(pos, rhs)?;
+        let Some(rhs) = line.as_sectionable().and_then(|info| info.rhs) else {
+            continue;
+        };
+
+        match (config.normalize_bind_mods, line.as_bind()) {
+            (true, Some(bind)) => {
+                // Only the modifier field is rewritten, everything from the first comma is kept
+                let rest = rhs.find(',').map_or("", |idx| &rhs[idx..]);
+
+                table.append_to_row(pos, &bind.normalized_mods(config.bind_mods_separator))?;
+                table.append_to_row(pos, &normalize_all(rest))?;
+            }
+            _ => {
+                table.append_to_row(pos, &normalize_all(rhs))?;
+            }
         }
     }
 
@@ -296,3 +385,27 @@ pub fn update_comment_column(config: Config, lines: &Rc<[Line]>, table: &mut Tab
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_file_recovering() {
+        let file = "general {\n  gaps_in=5\n   =oops  \n  border_size =2\n}\nbind=SUPER,Q,killactive\ndecoration {\n";
+
+        // The defaults of the command line
+        let config = Config {
+            indent_width: 2,
+            ..Config::default()
+        };
+
+        let (formatted, errors) = format_file_recovering(file, config).unwrap();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            formatted,
+            "general {\n  gaps_in     = 5\n   =oops  \n  border_size = 2\n}\n\nbind = SUPER,Q,killactive\ndecoration {\n"
+        );
+    }
+}
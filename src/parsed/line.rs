@@ -1,9 +1,11 @@
 use std::rc::Rc;
 
-use crate::{grammar::Rule, parse::ParseIterator};
+use crate::cst::{Element, Node};
+use crate::{grammar::Rule, parsed::bind::Bind};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct LineInfo<'a> {
+    pub bind: Option<Bind<'a>>,
     pub category_id: u16,
     pub comment_hashes: Option<&'a str>,
     pub comment_text: Option<&'a str>,
@@ -25,6 +27,7 @@ pub enum Line<'a> {
 impl<'a> Line<'a> {
     pub fn comment(category_id: u16, indent: u8, text: &'a str) -> Self {
         Self::Comment(LineInfo {
+            bind: None,
             group_id: u16::MAX,
             category_id: if indent == 0 { u16::MAX } else { category_id },
             indent,
@@ -37,6 +40,7 @@ impl<'a> Line<'a> {
 
     pub fn assignment(lhs: &'a str) -> Self {
         Self::Sectioned(LineInfo {
+            bind: None,
             group_id: u16::MAX,
             category_id: u16::MAX,
             indent: 0,
@@ -49,6 +53,7 @@ impl<'a> Line<'a> {
 
     pub fn bind(category_id: u16, indent: u8, lhs: &'a str) -> Self {
         Self::Sectioned(LineInfo {
+            bind: None,
             group_id: u16::MAX,
             category_id: if indent == 0 { u16::MAX } else { category_id },
             indent,
@@ -61,6 +66,7 @@ impl<'a> Line<'a> {
 
     pub fn category_start(category_id: u16, indent: u8, category_ident: &'a str) -> Self {
         Self::CategoryStart(LineInfo {
+            bind: None,
             group_id: u16::MAX,
             category_id,
             indent,
@@ -71,12 +77,13 @@ impl<'a> Line<'a> {
         })
     }
 
-    pub fn category_end(category_id: u16, indent: u8) -> Self {
+    pub fn category_end(category_id: u16, indent: u8, category_end: &'a str) -> Self {
         Self::CategoryEnd(LineInfo {
+            bind: None,
             group_id: u16::MAX,
             category_id,
             indent,
-            lhs: "}",
+            lhs: category_end,
             rhs: None,
             comment_hashes: None,
             comment_text: None,
@@ -141,10 +148,43 @@ impl<'a> Line<'a> {
             _ => panic!("can only set_rhs on a sectionable"),
         }
     }
+
+    pub fn set_bind(&mut self) {
+        match self {
+            Self::Sectioned(line) => {
+                line.bind = Bind::parse(line.lhs, line.rhs.unwrap_or_default());
+            }
+            _ => panic!("can only set_bind on a sectionable"),
+        }
+    }
+
+    pub fn as_bind(&self) -> Option<&Bind<'a>> {
+        self.as_sectionable().and_then(|info| info.bind.as_ref())
+    }
 }
 
-pub fn get_lines(pairs: ParseIterator) -> Rc<[Line]> {
-    let mut lines = get_lines_inner(pairs.map(|pair| (pair.as_rule(), pair.as_span().as_str())));
+/// The rule and text of every node lines are built from, in order. Variables and expressions
+/// inside a value are part of it, so values are not descended into.
+fn line_nodes<'a>(node: &Node<'a>, found: &mut Vec<(Rule, &'a str)>) {
+    for child in &node.children {
+        let Element::Node(child) = child else {
+            continue;
+        };
+
+        found.push((child.rule, child.text));
+
+        if !matches!(child.rule, Rule::bind_rhs | Rule::variable_expression) {
+            line_nodes(child, found);
+        }
+    }
+}
+
+/// The lines of a tree from [`crate::cst::parse`].
+pub fn get_lines<'a>(tree: &Node<'a>) -> Rc<[Line<'a>]> {
+    let mut nodes = vec![];
+    line_nodes(tree, &mut nodes);
+
+    let mut lines = get_lines_inner(nodes.into_iter());
 
     // Remove trailing newlines at EOF
     while matches!(lines.last(), Some(Line::Newline)) {
@@ -190,8 +230,9 @@ fn get_lines_inner<'a>(pairs: impl Iterator<Item = (Rule, &'a str)>) -> Vec<Line
         let adding_contentful_line_after_category_end =
             matches!(lines.last(), Some(Line::CategoryEnd(_))) && is_contentful_line;
 
-        let was_contentful_line = !matches!(lines.last(), Some(Line::Newline))
-            && !matches!(lines.last(), Some(Line::CategoryEnd(_)));
+        let was_contentful_line = lines
+            .last()
+            .is_some_and(|last| !matches!(last, Line::Newline | Line::CategoryEnd(_)));
 
         let adding_category_start_after_contentful_line =
             was_contentful_line && matches!(rule, Rule::category);
@@ -229,6 +270,7 @@ fn get_lines_inner<'a>(pairs: impl Iterator<Item = (Rule, &'a str)>) -> Vec<Line
             | Rule::EOI
             | Rule::bind
             | Rule::bind_rule
+            | Rule::category_bind_ident
             | Rule::comment
             | Rule::assignment => {
                 continue;
@@ -256,7 +298,11 @@ fn get_lines_inner<'a>(pairs: impl Iterator<Item = (Rule, &'a str)>) -> Vec<Line
             Rule::variable_ident => {
                 line = Line::assignment(span_str.trim_end());
             }
-            Rule::bind_rhs | Rule::variable_expression => line.set_rhs(span_str.trim_end()),
+            Rule::bind_rhs => {
+                line.set_rhs(span_str.trim_end());
+                line.set_bind();
+            }
+            Rule::variable_expression => line.set_rhs(span_str.trim_end()),
             Rule::category_ident => {
                 line = Line::category_start(category_id, indent, span_str.trim_end());
                 indent = indent.checked_add(1).expect("exceeded max indentation");
@@ -266,7 +312,7 @@ fn get_lines_inner<'a>(pairs: impl Iterator<Item = (Rule, &'a str)>) -> Vec<Line
                     .checked_sub(1)
                     .expect("added category end without starting a category");
 
-                line = Line::category_end(category_id, indent);
+                line = Line::category_end(category_id, indent, span_str);
 
                 if indent == 0 {
                     category_id += 1;
@@ -279,5 +325,40 @@ fn get_lines_inner<'a>(pairs: impl Iterator<Item = (Rule, &'a str)>) -> Vec<Line
         }
     }
 
+    // The last line, when the file doesn't end with a newline
+    if line != Line::Newline {
+        lines.push(line);
+    }
+
     lines
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cst;
+
+    #[test]
+    fn test_variables_in_values() {
+        let tree =
+            cst::parse("$a = $b\n$c = x  $d ## y\nbind = $mod, Q, exec, $terminal\n").unwrap();
+        let lines = get_lines(&tree);
+
+        let sectioned = lines
+            .iter()
+            .filter_map(|line| match line {
+                Line::Sectioned(info) => Some((info.lhs, info.rhs)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            sectioned,
+            [
+                ("$a", Some("$b")),
+                ("$c", Some("x  $d ## y")),
+                ("bind", Some("$mod, Q, exec, $terminal")),
+            ]
+        );
+    }
+}
@@ -1,2 +1,3 @@
+pub mod bind;
 pub mod format;
 pub mod line;
@@ -0,0 +1,330 @@
+use std::fmt::Write;
+
+use serde_json::{Value, json};
+
+use crate::diagnostic::{Diagnostic, Location, Severity};
+
+/// A kind of diagnostic, identified by its code.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rule {
+    pub code: &'static str,
+    pub severity: Severity,
+    pub description: &'static str,
+}
+
+const fn rule(code: &'static str, severity: Severity, description: &'static str) -> Rule {
+    Rule {
+        code,
+        severity,
+        description,
+    }
+}
+
+/// Every diagnostic code that is reported. Codes are stable, so tools can rely on them.
+#[rustfmt::skip]
+pub const RULES: &[Rule] = &[
+    rule("parse-error", Severity::Error, "The file is not valid hyprlang and could not be parsed."),
+    rule("source-not-found", Severity::Warning, "A file pulled in with `source = ...` does not exist or could not be read."),
+    rule("duplicate-bind", Severity::Warning, "The same key combination is bound more than once in the same submap, so only one of the binds takes effect."),
+    rule("undefined-variable", Severity::Error, "A variable is used but never defined."),
+    rule("used-before-definition", Severity::Warning, "A `$variable` is used before the line that defines it."),
+    rule("redefined-variable", Severity::Warning, "A `$variable` is defined more than once, so the earlier values are never used after the later definition."),
+    rule("unused-variable", Severity::Warning, "A `$variable` is defined but never used."),
+    rule("division-by-zero", Severity::Error, "A `{{ }}` expression divides by zero."),
+    rule("invalid-expression", Severity::Error, "A `{{ }}` expression is not valid arithmetic, or uses a variable that is not a number."),
+    rule("invalid-value", Severity::Error, "An option is set to a value its type does not accept, such as text for a number."),
+    rule("unknown-category", Severity::Warning, "A category is not known to the program the config is for, usually because of a typo."),
+    rule("unknown-option", Severity::Warning, "An option is not known to the program the config is for, usually because of a typo."),
+    rule("removed-option", Severity::Warning, "An option was removed in a Hyprland release and no longer has any effect."),
+    rule("migrated", Severity::Note, "An option was renamed in a Hyprland release and has been migrated to its new name."),
+    rule("would-reformat", Severity::Note, "The file is not formatted the way the formatter would format it."),
+];
+
+fn range(location: &Location) -> Value {
+    json!({
+        "start": { "line": location.start.line, "column": location.start.column },
+        "end": { "line": location.end.line, "column": location.end.column },
+    })
+}
+
+/// A diagnostic as a single JSON object, with 1-based lines and columns.
+pub fn to_json(diagnostic: &Diagnostic) -> Value {
+    json!({
+        "type": "diagnostic",
+        "file": diagnostic.location.name(),
+        "range": range(&diagnostic.location),
+        "severity": diagnostic.severity.to_string(),
+        "code": diagnostic.code,
+        "message": diagnostic.message,
+        "related": diagnostic.related.iter().map(|(location, message)| json!({
+            "file": location.name(),
+            "range": range(location),
+            "message": message,
+        })).collect::<Vec<_>>(),
+    })
+}
+
+/// The number of `files` checked and of `diagnostics` of each severity.
+pub fn summary(diagnostics: &[Diagnostic], files: usize) -> Value {
+    let count = |severity| {
+        diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == severity)
+            .count()
+    };
+
+    json!({
+        "type": "summary",
+        "files": files,
+        "errors": count(Severity::Error),
+        "warnings": count(Severity::Warning),
+        "notes": count(Severity::Note),
+    })
+}
+
+/// `location`'s file as a relative or absolute URI reference, percent-encoding anything outside
+/// the unreserved characters and path separators.
+fn uri(location: &Location) -> String {
+    let Some(path) = &location.path else {
+        return "stdin".to_string();
+    };
+
+    path.display()
+        .to_string()
+        .replace('\\', "/")
+        .bytes()
+        .fold(String::new(), |mut uri, byte| {
+            if byte.is_ascii_alphanumeric() || b"-._~/:".contains(&byte) {
+                uri.push(byte as char);
+            } else {
+                let _ = write!(uri, "%{byte:02X}");
+            }
+
+            uri
+        })
+}
+
+/// REF
+/// - <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Note => "note",
+    }
+}
+
+fn sarif_location(location: &Location) -> Value {
+    json!({
+        "physicalLocation": {
+            "artifactLocation": { "uri": uri(location) },
+            "region": {
+                "startLine": location.start.line,
+                "startColumn": location.start.column,
+                "endLine": location.end.line,
+                "endColumn": location.end.column,
+            },
+        },
+    })
+}
+
+/// All `diagnostics` as a single SARIF 2.1.0 log, with every rule described.
+pub fn sarif(diagnostics: &[Diagnostic]) -> Value {
+    let rules = RULES
+        .iter()
+        .map(|rule| {
+            json!({
+                "id": rule.code,
+                "shortDescription": { "text": rule.description },
+                "defaultConfiguration": { "level": sarif_level(rule.severity) },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let results = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let mut result = json!({
+                "ruleId": diagnostic.code,
+                "level": sarif_level(diagnostic.severity),
+                "message": { "text": diagnostic.message },
+                "locations": [sarif_location(&diagnostic.location)],
+            });
+
+            if let Some(index) = RULES.iter().position(|rule| rule.code == diagnostic.code) {
+                result["ruleIndex"] = index.into();
+            }
+
+            if !diagnostic.related.is_empty() {
+                result["relatedLocations"] = diagnostic
+                    .related
+                    .iter()
+                    .enumerate()
+                    .map(|(id, (location, message))| {
+                        let mut related = sarif_location(location);
+                        related["id"] = id.into();
+                        related["message"] = json!({ "text": message });
+                        related
+                    })
+                    .collect();
+            }
+
+            result
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+fn escape_xml(text: &str) -> String {
+    text.chars().fold(String::new(), |mut escaped, char| {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            char => escaped.push(char),
+        }
+
+        escaped
+    })
+}
+
+/// All `diagnostics` as a Checkstyle XML report, grouped by file in the order they first appear.
+///
+/// Checkstyle has no notes, so they are reported as `info`.
+pub fn checkstyle(diagnostics: &[Diagnostic]) -> String {
+    let mut files: Vec<(String, Vec<&Diagnostic>)> = vec![];
+
+    for diagnostic in diagnostics {
+        let name = diagnostic.location.name();
+
+        match files.iter_mut().find(|(file, _)| *file == name) {
+            Some((_, diagnostics)) => diagnostics.push(diagnostic),
+            None => files.push((name, vec![diagnostic])),
+        }
+    }
+
+    let mut xml =
+        String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n");
+
+    for (file, diagnostics) in files {
+        let _ = writeln!(xml, "  <file name=\"{}\">", escape_xml(&file));
+
+        for diagnostic in diagnostics {
+            let severity = match diagnostic.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Note => "info",
+            };
+
+            let _ = writeln!(
+                xml,
+                "    <error line=\"{}\" column=\"{}\" severity=\"{severity}\" message=\"{}\" source=\"{}.{}\"/>",
+                diagnostic.location.start.line,
+                diagnostic.location.start.column,
+                escape_xml(&diagnostic.message),
+                env!("CARGO_PKG_NAME"),
+                diagnostic.code,
+            );
+        }
+
+        xml.push_str("  </file>\n");
+    }
+
+    xml.push_str("</checkstyle>\n");
+
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::Position;
+
+    #[test]
+    fn test_to_json() {
+        let location = Location {
+            path: None,
+            start: Position { line: 2, column: 3 },
+            end: Position { line: 2, column: 7 },
+        };
+        let diagnostic = Diagnostic::new("unused-variable", Severity::Warning, location, "unused");
+
+        assert_eq!(
+            to_json(&diagnostic),
+            json!({
+                "type": "diagnostic",
+                "file": "<stdin>",
+                "range": {
+                    "start": { "line": 2, "column": 3 },
+                    "end": { "line": 2, "column": 7 },
+                },
+                "severity": "warning",
+                "code": "unused-variable",
+                "message": "unused",
+                "related": [],
+            })
+        );
+        assert_eq!(summary(&[diagnostic], 1)["warnings"], 1);
+    }
+
+    #[test]
+    fn test_sarif_and_checkstyle() {
+        let location = Location {
+            path: Some("hypr/my config.conf".into()),
+            start: Position { line: 4, column: 1 },
+            end: Position { line: 4, column: 9 },
+        };
+        let diagnostic = Diagnostic::new(
+            "invalid-value",
+            Severity::Error,
+            location,
+            "expected int, found \"a<b\"",
+        );
+        let diagnostics = [diagnostic];
+
+        let sarif = sarif(&diagnostics);
+        let result = &sarif["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "invalid-value");
+        assert_eq!(
+            sarif["runs"][0]["tool"]["driver"]["rules"][9]["id"],
+            "invalid-value"
+        );
+        assert_eq!(result["ruleIndex"], 9);
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "hypr/my%20config.conf"
+        );
+
+        assert_eq!(
+            checkstyle(&diagnostics),
+            [
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>",
+                "<checkstyle version=\"4.3\">",
+                "  <file name=\"hypr/my config.conf\">",
+                "    <error line=\"4\" column=\"1\" severity=\"error\" message=\"expected int, found &quot;a&lt;b&quot;\" source=\"hyprlang-fmt.invalid-value\"/>",
+                "  </file>",
+                "</checkstyle>",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,32 @@
+//! Categories and options of `hypridle.conf`.
+//!
+//! REF
+//! - <https://wiki.hyprland.org/Hypr-Ecosystem/hypridle/>
+
+use super::Type::{Bool, Enum, Int, Keyword, Str};
+use super::{Schema, Spec, spec};
+
+pub static HYPRIDLE: Schema = Schema {
+    specs: SPECS,
+    open_categories: &[],
+    aliases: &[],
+};
+
+#[rustfmt::skip]
+const SPECS: &[Spec] = &[
+    spec("source", Keyword, "", "Include another config file."),
+    spec("general:lock_cmd", Str, "", "Command to run when receiving a dbus lock event, e.g. `loginctl lock-session`."),
+    spec("general:unlock_cmd", Str, "", "Command to run when receiving a dbus unlock event."),
+    spec("general:on_lock_cmd", Str, "", "Command to run when the session gets locked by a lock screen app."),
+    spec("general:on_unlock_cmd", Str, "", "Command to run when the session gets unlocked by a lock screen app."),
+    spec("general:before_sleep_cmd", Str, "", "Command to run when receiving a dbus prepare_sleep event."),
+    spec("general:after_sleep_cmd", Str, "", "Command to run when receiving a dbus post prepare_sleep event."),
+    spec("general:ignore_dbus_inhibit", Bool, "false", "Whether to ignore dbus-sent idle inhibit events, e.g. from firefox or steam."),
+    spec("general:ignore_systemd_inhibit", Bool, "false", "Whether to ignore `systemd-inhibit --what=idle` inhibitors."),
+    spec("general:ignore_wayland_inhibit", Bool, "false", "Whether to ignore Wayland protocol idle inhibitors."),
+    spec("general:inhibit_sleep", Enum(&["0", "1", "2", "3"]), "2", "Sleep inhibition: 0 none, 1 normal, 2 auto, 3 until the session is locked."),
+    spec("listener:timeout", Int, "", "Idle time in seconds before `on-timeout` runs."),
+    spec("listener:on-timeout", Str, "", "Command to run when the timeout has passed."),
+    spec("listener:on-resume", Str, "", "Command to run when activity is detected after the timeout has fired."),
+    spec("listener:ignore_inhibit", Bool, "false", "Whether to ignore idle inhibitors for this listener."),
+];
@@ -0,0 +1,346 @@
+//! Categories, options and keywords of `hyprland.conf`.
+//!
+//! REF
+//! - <https://wiki.hyprland.org/Configuring/Variables/>
+//! - <https://wiki.hyprland.org/Configuring/Keywords/>
+
+use super::Type::{Bool, Color, Enum, Float, Gaps, Gradient, Int, Keyword, Str, Vec2};
+use super::{Schema, Spec, spec};
+
+const ZERO_TO_TWO: &[&str] = &["0", "1", "2"];
+const ZERO_TO_THREE: &[&str] = &["0", "1", "2", "3"];
+const ZERO_OR_ONE: &[&str] = &["0", "1"];
+
+pub static HYPRLAND: Schema = Schema {
+    specs: SPECS,
+    open_categories: &["plugin"],
+    aliases: &[
+        ("device", "input"),
+        ("device", "input:touchpad"),
+        ("device", "input:tablet"),
+    ],
+};
+
+#[rustfmt::skip]
+const SPECS: &[Spec] = &[
+    // Keywords
+    spec("monitor", Keyword, "", "Configure a monitor: `name, resolution, position, scale`."),
+    spec("workspace", Keyword, "", "Set rules for a workspace."),
+    spec("exec", Keyword, "", "Run a command on every reload."),
+    spec("exec-once", Keyword, "", "Run a command once, on launch."),
+    spec("execr", Keyword, "", "Run a raw shell command on every reload."),
+    spec("execr-once", Keyword, "", "Run a raw shell command once, on launch."),
+    spec("exec-shutdown", Keyword, "", "Run a command on shutdown."),
+    spec("env", Keyword, "", "Set an environment variable: `NAME, value`."),
+    spec("envd", Keyword, "", "Set an environment variable and export it to D-Bus."),
+    spec("source", Keyword, "", "Include another config file."),
+    spec("windowrule", Keyword, "", "Set a rule for matching windows."),
+    spec("windowrulev2", Keyword, "", "Set a rule for matching windows (deprecated syntax)."),
+    spec("layerrule", Keyword, "", "Set a rule for matching layer surfaces."),
+    spec("unbind", Keyword, "", "Remove a bind: `MODS, key`."),
+    spec("submap", Keyword, "", "Start a submap, or `reset` to return to the global one."),
+    spec("blurls", Keyword, "", "Blur the layer surface with the given namespace."),
+    spec("plugin", Keyword, "", "Load a plugin from the given path."),
+    spec("permission", Keyword, "", "Grant or deny a permission: `regex, permission, mode`."),
+    spec("gesture", Keyword, "", "Bind a touchpad gesture: `fingers, direction, action`."),
+    spec("animations:bezier", Keyword, "", "Define a bezier curve: `name, X0, Y0, X1, Y1`."),
+    spec("animations:animation", Keyword, "", "Configure an animation: `name, onoff, speed, curve[, style]`."),
+    spec("device:name", Str, "", "The name of the device to configure, as shown by `hyprctl devices`."),
+    spec("device:enabled", Bool, "true", "Whether the device is enabled."),
+    spec("device:keybinds", Bool, "true", "Whether the device can trigger binds."),
+    // general
+    spec("general:border_size", Int, "1", "Size of the border around windows."),
+    spec("general:gaps_in", Gaps, "5", "Gaps between windows, with CSS style values."),
+    spec("general:gaps_out", Gaps, "20", "Gaps between windows and monitor edges, with CSS style values."),
+    spec("general:gaps_workspaces", Int, "0", "Gaps between workspaces, stacked with `gaps_out`."),
+    spec("general:col.inactive_border", Gradient, "0xff444444", "Border color for inactive windows."),
+    spec("general:col.active_border", Gradient, "0xffffffff", "Border color for the active window."),
+    spec("general:col.nogroup_border", Gradient, "0xffffaaff", "Inactive border color for windows that cannot be added to a group."),
+    spec("general:col.nogroup_border_active", Gradient, "0xffff00ff", "Active border color for windows that cannot be added to a group."),
+    spec("general:layout", Str, "dwindle", "Which layout to use, `dwindle` or `master`."),
+    spec("general:no_focus_fallback", Bool, "false", "Whether moving focus in a direction with no window wraps around."),
+    spec("general:resize_on_border", Bool, "false", "Enables resizing windows by clicking and dragging on borders and gaps."),
+    spec("general:extend_border_grab_area", Int, "15", "Extends the area around the border where you can click and drag, with `resize_on_border`."),
+    spec("general:hover_icon_on_border", Bool, "true", "Show a cursor icon when hovering over borders, with `resize_on_border`."),
+    spec("general:allow_tearing", Bool, "false", "Master switch for allowing tearing to occur."),
+    spec("general:resize_corner", Enum(&["0", "1", "2", "3", "4"]), "0", "Force floating windows to resize from a corner: 1 top left, then clockwise. 0 disables."),
+    spec("general:snap:enabled", Bool, "false", "Enable snapping for floating windows."),
+    spec("general:snap:window_gap", Int, "10", "Minimum gap in pixels between windows before snapping."),
+    spec("general:snap:monitor_gap", Int, "10", "Minimum gap in pixels between a window and a monitor edge before snapping."),
+    spec("general:snap:border_overlap", Bool, "false", "Snap windows so that only one border's worth of space is between them."),
+    // decoration
+    spec("decoration:rounding", Int, "0", "Rounded corners' radius in layout pixels."),
+    spec("decoration:rounding_power", Float, "2.0", "Curve used for rounding corners, 2.0 being a circle."),
+    spec("decoration:active_opacity", Float, "1.0", "Opacity of active windows, from 0.0 to 1.0."),
+    spec("decoration:inactive_opacity", Float, "1.0", "Opacity of inactive windows, from 0.0 to 1.0."),
+    spec("decoration:fullscreen_opacity", Float, "1.0", "Opacity of fullscreen windows, from 0.0 to 1.0."),
+    spec("decoration:dim_inactive", Bool, "false", "Enables dimming of inactive windows."),
+    spec("decoration:dim_strength", Float, "0.5", "How much inactive windows should be dimmed, from 0.0 to 1.0."),
+    spec("decoration:dim_special", Float, "0.2", "How much to dim the rest of the screen when a special workspace is open."),
+    spec("decoration:dim_around", Float, "0.4", "How much the `dimaround` window rule should dim by."),
+    spec("decoration:screen_shader", Str, "", "Path to a custom fragment shader to apply at the end of rendering."),
+    spec("decoration:border_part_of_window", Bool, "true", "Whether the window border should be a part of the window."),
+    spec("decoration:blur:enabled", Bool, "true", "Enable kawase window background blur."),
+    spec("decoration:blur:size", Int, "8", "Blur size (distance)."),
+    spec("decoration:blur:passes", Int, "1", "The amount of passes to perform."),
+    spec("decoration:blur:ignore_opacity", Bool, "true", "Make the blur layer ignore the opacity of the window."),
+    spec("decoration:blur:new_optimizations", Bool, "true", "Whether to enable further optimizations to the blur."),
+    spec("decoration:blur:xray", Bool, "false", "Make floating windows ignore tiled windows in their blur."),
+    spec("decoration:blur:noise", Float, "0.0117", "How much noise to apply, from 0.0 to 1.0."),
+    spec("decoration:blur:contrast", Float, "0.8916", "Contrast modulation for blur, from 0.0 to 2.0."),
+    spec("decoration:blur:brightness", Float, "0.8172", "Brightness modulation for blur, from 0.0 to 2.0."),
+    spec("decoration:blur:vibrancy", Float, "0.1696", "Increase saturation of blurred colors, from 0.0 to 1.0."),
+    spec("decoration:blur:vibrancy_darkness", Float, "0.0", "How strong the effect of `vibrancy` is on dark areas, from 0.0 to 1.0."),
+    spec("decoration:blur:special", Bool, "false", "Whether to blur behind the special workspace."),
+    spec("decoration:blur:popups", Bool, "false", "Whether to blur popups, e.g. right-click menus."),
+    spec("decoration:blur:popups_ignorealpha", Float, "0.2", "Works like `ignorealpha` in layer rules, for popups."),
+    spec("decoration:blur:input_methods", Bool, "false", "Whether to blur input methods, e.g. fcitx5."),
+    spec("decoration:blur:input_methods_ignorealpha", Float, "0.2", "Works like `ignorealpha` in layer rules, for input methods."),
+    spec("decoration:shadow:enabled", Bool, "true", "Enable drop shadows on windows."),
+    spec("decoration:shadow:range", Int, "4", "Shadow range (size) in layout pixels."),
+    spec("decoration:shadow:render_power", Enum(&["1", "2", "3", "4"]), "3", "In what power to render the falloff, more power means a faster falloff."),
+    spec("decoration:shadow:sharp", Bool, "false", "Whether the shadow should be sharp, like an infinitely high render power."),
+    spec("decoration:shadow:ignore_window", Bool, "true", "Whether the shadow is only drawn outside of the window."),
+    spec("decoration:shadow:color", Color, "0xee1a1a1a", "The shadow's color. Alpha dictates its opacity."),
+    spec("decoration:shadow:color_inactive", Color, "", "Inactive shadow color, defaults to `color`."),
+    spec("decoration:shadow:offset", Vec2, "0 0", "The shadow's offset."),
+    spec("decoration:shadow:scale", Float, "1.0", "The shadow's scale, from 0.0 to 1.0."),
+    // animations
+    spec("animations:enabled", Bool, "true", "Enable animations."),
+    spec("animations:first_launch_animation", Bool, "true", "Enable the first launch animation."),
+    spec("animations:workspace_wraparound", Bool, "false", "Animate between the first and last workspaces as if they were adjacent."),
+    // input
+    spec("input:kb_model", Str, "", "XKB keymap model."),
+    spec("input:kb_layout", Str, "us", "XKB keymap layout."),
+    spec("input:kb_variant", Str, "", "XKB keymap variant."),
+    spec("input:kb_options", Str, "", "XKB keymap options."),
+    spec("input:kb_rules", Str, "", "XKB keymap rules."),
+    spec("input:kb_file", Str, "", "Path to a custom `.xkb` file."),
+    spec("input:numlock_by_default", Bool, "false", "Engage numlock by default."),
+    spec("input:resolve_binds_by_sym", Bool, "false", "Resolve binds by the symbol of the key, rather than the key of the first layout."),
+    spec("input:repeat_rate", Int, "25", "The repeat rate for held-down keys, in repeats per second."),
+    spec("input:repeat_delay", Int, "600", "Delay before a held-down key is repeated, in milliseconds."),
+    spec("input:sensitivity", Float, "0.0", "Mouse input sensitivity, from -1.0 to 1.0."),
+    spec("input:accel_profile", Str, "", "Cursor acceleration profile: `adaptive`, `flat` or `custom`."),
+    spec("input:force_no_accel", Bool, "false", "Force no cursor acceleration, bypassing most pointer settings."),
+    spec("input:left_handed", Bool, "false", "Switch the left and right mouse buttons."),
+    spec("input:scroll_points", Str, "", "The scroll acceleration profile, when `accel_profile` is `custom`."),
+    spec("input:scroll_method", Str, "", "Scroll method: `2fg`, `edge`, `on_button_down` or `no_scroll`."),
+    spec("input:scroll_button", Int, "0", "Button used for `on_button_down` scrolling, 0 for the default."),
+    spec("input:scroll_button_lock", Bool, "false", "Whether the scroll button only needs a click to toggle scrolling."),
+    spec("input:scroll_factor", Float, "1.0", "Multiplier for mouse scroll distance."),
+    spec("input:natural_scroll", Bool, "false", "Invert the scrolling direction of mice."),
+    spec("input:follow_mouse", Enum(ZERO_TO_THREE), "1", "How keyboard focus follows the mouse, from 0 (not at all) to 3 (fully separated)."),
+    spec("input:follow_mouse_threshold", Float, "0.0", "The smallest distance in logical pixels the mouse needs to travel to change focus."),
+    spec("input:focus_on_close", Enum(ZERO_OR_ONE), "0", "Focus the next window in the same direction (0) or under the cursor (1) on close."),
+    spec("input:mouse_refocus", Bool, "true", "Whether mouse focus changes focus when crossing into a window of the same layout."),
+    spec("input:float_switch_override_focus", Enum(ZERO_TO_TWO), "1", "Whether focus follows the mouse when switching between tiled and floating windows."),
+    spec("input:special_fallthrough", Bool, "false", "Whether focus can go to windows below an open special workspace."),
+    spec("input:off_window_axis_events", Enum(ZERO_TO_THREE), "1", "How scroll events outside of the focused window are handled."),
+    spec("input:emulate_discrete_scroll", Enum(ZERO_TO_TWO), "1", "Emulate discrete scrolling from high resolution scrolling events."),
+    spec("input:touchpad:disable_while_typing", Bool, "true", "Disable the touchpad while typing."),
+    spec("input:touchpad:natural_scroll", Bool, "false", "Invert the scrolling direction of touchpads."),
+    spec("input:touchpad:scroll_factor", Float, "1.0", "Multiplier for touchpad scroll distance."),
+    spec("input:touchpad:middle_button_emulation", Bool, "false", "Send a middle click when clicking left and right at the same time."),
+    spec("input:touchpad:tap_button_map", Str, "", "Button mapping for tap-to-click, `lrm` or `lmr`."),
+    spec("input:touchpad:clickfinger_behavior", Bool, "false", "Use the number of fingers rather than the click position to pick the button."),
+    spec("input:touchpad:tap-to-click", Bool, "true", "Tapping with 1, 2 or 3 fingers sends a left, right or middle click."),
+    spec("input:touchpad:drag_lock", Enum(ZERO_TO_TWO), "0", "Whether lifting the finger during a tap-and-drag keeps the drag going."),
+    spec("input:touchpad:tap-and-drag", Bool, "true", "Enable tap-and-drag mode."),
+    spec("input:touchdevice:transform", Int, "-1", "Transform the touch input, -1 to follow the monitor."),
+    spec("input:touchdevice:output", Str, "[[Auto]]", "The monitor to bind touch input to."),
+    spec("input:touchdevice:enabled", Bool, "true", "Whether touch input is enabled."),
+    spec("input:tablet:transform", Int, "-1", "Transform the tablet input, -1 to follow the monitor."),
+    spec("input:tablet:output", Str, "", "The monitor to bind tablets to, or `current`."),
+    spec("input:tablet:region_position", Vec2, "0 0", "Position of the mapped region in monitor layout."),
+    spec("input:tablet:absolute_region_position", Bool, "false", "Whether `region_position` is an absolute position in the monitor layout."),
+    spec("input:tablet:region_size", Vec2, "0 0", "Size of the mapped region, 0 0 for the whole monitor."),
+    spec("input:tablet:relative_input", Bool, "false", "Whether the input should be relative."),
+    spec("input:tablet:left_handed", Bool, "false", "Rotate the tablet 180 degrees."),
+    spec("input:tablet:active_area_size", Vec2, "0 0", "Size of the tablet's active area in mm."),
+    spec("input:tablet:active_area_position", Vec2, "0 0", "Position of the active area in mm."),
+    // gestures
+    spec("gestures:workspace_swipe", Bool, "false", "Enable workspace swipe gestures on touchpads."),
+    spec("gestures:workspace_swipe_fingers", Int, "3", "How many fingers for the touchpad gesture."),
+    spec("gestures:workspace_swipe_min_fingers", Bool, "false", "Treat `workspace_swipe_fingers` as the minimum number of fingers."),
+    spec("gestures:workspace_swipe_distance", Int, "300", "Distance of the touchpad gesture in pixels."),
+    spec("gestures:workspace_swipe_touch", Bool, "false", "Enable workspace swiping from the edge of a touchscreen."),
+    spec("gestures:workspace_swipe_invert", Bool, "true", "Invert the direction of touchpad swipes."),
+    spec("gestures:workspace_swipe_touch_invert", Bool, "false", "Invert the direction of touchscreen swipes."),
+    spec("gestures:workspace_swipe_min_speed_to_force", Int, "30", "Minimum speed in pixels per timepoint to force the change ignoring the cancel ratio."),
+    spec("gestures:workspace_swipe_cancel_ratio", Float, "0.5", "How much the swipe has to proceed to commit it, from 0.0 to 1.0."),
+    spec("gestures:workspace_swipe_create_new", Bool, "true", "Whether a swipe right on the last workspace creates a new one."),
+    spec("gestures:workspace_swipe_direction_lock", Bool, "true", "Lock the swipe direction once it exceeds the threshold."),
+    spec("gestures:workspace_swipe_direction_lock_threshold", Int, "10", "Pixels to swipe before the direction locks."),
+    spec("gestures:workspace_swipe_forever", Bool, "false", "Keep swiping past the neighbouring workspaces."),
+    spec("gestures:workspace_swipe_use_r", Bool, "false", "Use the `r` prefix instead of `m` when finding the target workspace."),
+    // group
+    spec("group:auto_group", Bool, "true", "Whether new windows are automatically grouped into the focused unlocked group."),
+    spec("group:insert_after_current", Bool, "true", "Whether new windows in a group spawn after the current window or at the end."),
+    spec("group:focus_removed_window", Bool, "true", "Whether to focus a window that was just moved out of a group."),
+    spec("group:drag_into_group", Enum(ZERO_TO_TWO), "1", "Whether dragging a window into an unlocked group merges them."),
+    spec("group:merge_groups_on_drag", Bool, "true", "Whether dragging a group into another merges them."),
+    spec("group:merge_groups_on_groupbar", Bool, "true", "Whether a window can be dropped on a groupbar to merge it."),
+    spec("group:merge_floated_into_tiled_on_groupbar", Bool, "false", "Whether floating windows merge into tiled groups through their groupbar."),
+    spec("group:group_on_movetoworkspace", Bool, "false", "Whether moving a window to a workspace groups it with the focused window there."),
+    spec("group:col.border_active", Gradient, "0x66ffff00", "Active group border color."),
+    spec("group:col.border_inactive", Gradient, "0x66777700", "Inactive group border color."),
+    spec("group:col.border_locked_active", Gradient, "0x66ff5500", "Active locked group border color."),
+    spec("group:col.border_locked_inactive", Gradient, "0x66775500", "Inactive locked group border color."),
+    spec("group:groupbar:enabled", Bool, "true", "Enables groupbars."),
+    spec("group:groupbar:font_family", Str, "", "Font used for titles, defaults to `misc:font_family`."),
+    spec("group:groupbar:font_size", Int, "8", "Font size of titles."),
+    spec("group:groupbar:font_weight_active", Str, "normal", "Font weight of the active title."),
+    spec("group:groupbar:font_weight_inactive", Str, "normal", "Font weight of inactive titles."),
+    spec("group:groupbar:gradients", Bool, "false", "Enables gradients."),
+    spec("group:groupbar:height", Int, "14", "Height of the groupbar."),
+    spec("group:groupbar:indicator_gap", Int, "0", "Height of the gap between the indicator and the title."),
+    spec("group:groupbar:indicator_height", Int, "3", "Height of the indicator."),
+    spec("group:groupbar:stacked", Bool, "false", "Render the groupbar as a vertical stack."),
+    spec("group:groupbar:priority", Int, "3", "Decoration priority of groupbars."),
+    spec("group:groupbar:render_titles", Bool, "true", "Whether to render titles in the groupbar decoration."),
+    spec("group:groupbar:text_offset", Int, "0", "Vertical offset of the title text."),
+    spec("group:groupbar:scrolling", Bool, "true", "Whether scrolling in the groupbar changes the active window."),
+    spec("group:groupbar:rounding", Int, "1", "How much to round the indicator."),
+    spec("group:groupbar:gradient_rounding", Int, "2", "How much to round the gradients."),
+    spec("group:groupbar:round_only_edges", Bool, "true", "Round only the indicator edges of the entire groupbar."),
+    spec("group:groupbar:gradient_round_only_edges", Bool, "true", "Round only the gradient edges of the entire groupbar."),
+    spec("group:groupbar:text_color", Color, "0xffffffff", "Color of the title text."),
+    spec("group:groupbar:col.active", Gradient, "0x66ffff00", "Active groupbar color."),
+    spec("group:groupbar:col.inactive", Gradient, "0x66777700", "Inactive groupbar color."),
+    spec("group:groupbar:col.locked_active", Gradient, "0x66ff5500", "Active locked groupbar color."),
+    spec("group:groupbar:col.locked_inactive", Gradient, "0x66775500", "Inactive locked groupbar color."),
+    spec("group:groupbar:gaps_in", Int, "2", "Gap size between groupbar indicators."),
+    spec("group:groupbar:gaps_out", Int, "2", "Gap size between the groupbar and the window."),
+    spec("group:groupbar:keep_upper_gap", Bool, "true", "Add or remove the upper gap."),
+    // misc
+    spec("misc:disable_hyprland_logo", Bool, "false", "Disables the random Hyprland logo and anime girl background."),
+    spec("misc:disable_splash_rendering", Bool, "false", "Disables the Hyprland splash rendering."),
+    spec("misc:col.splash", Color, "0xffffffff", "Changes the color of the splash text."),
+    spec("misc:font_family", Str, "Sans", "Font used for Hyprland's own text."),
+    spec("misc:splash_font_family", Str, "", "Font used for the splash text, defaults to `font_family`."),
+    spec("misc:force_default_wallpaper", Enum(&["-1", "0", "1", "2"]), "-1", "Enforce a default wallpaper, -1 for random and 0 or 1 to disable the anime mascot."),
+    spec("misc:vfr", Bool, "true", "Lower the amount of frames sent when nothing is happening on screen."),
+    spec("misc:vrr", Enum(ZERO_TO_THREE), "0", "Adaptive sync: 0 off, 1 on, 2 fullscreen only, 3 fullscreen games and videos."),
+    spec("misc:mouse_move_enables_dpms", Bool, "false", "Moving the mouse turns the monitors back on after DPMS off."),
+    spec("misc:key_press_enables_dpms", Bool, "false", "Pressing a key turns the monitors back on after DPMS off."),
+    spec("misc:always_follow_on_dnd", Bool, "true", "Keyboard focus follows the mouse during drag and drop."),
+    spec("misc:layers_hog_keyboard_focus", Bool, "true", "Keyboard-interactive layers keep their focus on mouse move."),
+    spec("misc:animate_manual_resizes", Bool, "false", "Animate manual window resizes and moves."),
+    spec("misc:animate_mouse_windowdragging", Bool, "false", "Animate windows being dragged by the mouse."),
+    spec("misc:disable_autoreload", Bool, "false", "Disable reloading the config when it changes."),
+    spec("misc:enable_swallow", Bool, "false", "Enable window swallowing."),
+    spec("misc:swallow_regex", Str, "", "The class regex of windows that can swallow others."),
+    spec("misc:swallow_exception_regex", Str, "", "The title regex of windows that must not be swallowed."),
+    spec("misc:focus_on_activate", Bool, "false", "Focus an app that requests to be focused."),
+    spec("misc:mouse_move_focuses_monitor", Bool, "true", "Whether moving the mouse onto another monitor focuses it."),
+    spec("misc:allow_session_lock_restore", Bool, "false", "Allow restarting a lockscreen app after it crashed."),
+    spec("misc:background_color", Color, "0x111111", "The background color, shown behind the wallpaper."),
+    spec("misc:close_special_on_empty", Bool, "true", "Close the special workspace when its last window is removed."),
+    spec("misc:new_window_takes_over_fullscreen", Enum(ZERO_TO_TWO), "0", "What a new window does over a fullscreen one: 0 behind, 1 take over, 2 unfullscreen."),
+    spec("misc:exit_window_retains_fullscreen", Bool, "false", "Whether closing a fullscreen window makes the next focused one fullscreen."),
+    spec("misc:initial_workspace_tracking", Enum(ZERO_TO_TWO), "1", "Whether windows open on the workspace they were launched from."),
+    spec("misc:middle_click_paste", Bool, "true", "Whether to enable middle-click paste."),
+    spec("misc:render_unfocused_fps", Int, "15", "The framerate to send to unfocused windows that request it."),
+    spec("misc:disable_xdg_env_checks", Bool, "false", "Disable the warning about misconfigured XDG environment variables."),
+    spec("misc:disable_hyprland_qtutils_check", Bool, "false", "Disable the warning about missing hyprland-qtutils."),
+    spec("misc:lockdead_screen_delay", Int, "1000", "Delay in ms after the lockscreen dies before showing the lockdead screen."),
+    spec("misc:enable_anr_dialog", Bool, "true", "Show a dialog when an app stops responding."),
+    spec("misc:anr_missed_pings", Int, "1", "Missed pings before an app is considered not responding."),
+    // binds
+    spec("binds:pass_mouse_when_bound", Bool, "false", "Pass mouse events to windows even when they trigger a bind."),
+    spec("binds:scroll_event_delay", Int, "300", "Time in ms between scroll events accepted by binds."),
+    spec("binds:workspace_back_and_forth", Bool, "false", "Switching to the current workspace goes back to the previous one."),
+    spec("binds:hide_special_on_workspace_change", Bool, "false", "Close the special workspace when changing workspaces."),
+    spec("binds:allow_workspace_cycles", Bool, "false", "Whether `workspace previous` cycles between the last workspaces."),
+    spec("binds:workspace_center_on", Enum(ZERO_OR_ONE), "0", "Whether switching workspaces centers the cursor on the workspace (0) or the last active window (1)."),
+    spec("binds:focus_preferred_method", Enum(ZERO_OR_ONE), "0", "Pick the window to focus by history (0) or by the longest shared edge (1)."),
+    spec("binds:ignore_group_lock", Bool, "false", "Whether moving windows into groups ignores group locks."),
+    spec("binds:movefocus_cycles_fullscreen", Bool, "false", "Whether `movefocus` cycles windows in a fullscreen workspace."),
+    spec("binds:movefocus_cycles_groupfirst", Bool, "false", "Whether `movefocus` cycles through group members first."),
+    spec("binds:disable_keybind_grabbing", Bool, "false", "Whether apps requesting keybind grabs are ignored."),
+    spec("binds:window_direction_monitor_fallback", Bool, "true", "Whether moving in a direction without windows falls back to the next monitor."),
+    spec("binds:allow_pin_fullscreen", Bool, "false", "Whether pinned windows can be made fullscreen."),
+    spec("binds:drag_threshold", Int, "0", "Movement in pixels before a click-and-drag is treated as a drag."),
+    // xwayland, opengl, render
+    spec("xwayland:enabled", Bool, "true", "Allow running X11 applications."),
+    spec("xwayland:use_nearest_neighbor", Bool, "true", "Use nearest-neighbor filtering for scaled X11 apps."),
+    spec("xwayland:force_zero_scaling", Bool, "false", "Force a scale of 1 on X11 windows on scaled monitors."),
+    spec("xwayland:create_abstract_socket", Bool, "false", "Create the abstract Xwayland socket."),
+    spec("opengl:nvidia_anti_flicker", Bool, "true", "Reduce flickering on NVIDIA at the cost of performance."),
+    spec("render:direct_scanout", Enum(ZERO_TO_TWO), "0", "Enables direct scanout: 0 off, 1 on, 2 auto for games."),
+    spec("render:expand_undersized_textures", Bool, "true", "Whether to expand undersized textures along the edge."),
+    spec("render:xp_mode", Bool, "false", "Disable back buffer and bottom layer rendering."),
+    spec("render:ctm_animation", Enum(ZERO_TO_TWO), "2", "Whether to animate color transform matrix changes: 0 off, 1 on, 2 auto."),
+    spec("render:cm_fs_passthrough", Enum(ZERO_TO_TWO), "2", "Pass color management through for fullscreen apps: 0 off, 1 always, 2 HDR only."),
+    spec("render:cm_enabled", Bool, "true", "Whether the color management pipeline is enabled."),
+    spec("render:send_content_type", Bool, "true", "Report the content type to allow monitor profile autoswitch."),
+    spec("render:cm_auto_hdr", Enum(ZERO_TO_TWO), "1", "Switch to HDR for fullscreen HDR content: 0 off, 1 hdr, 2 hdredid."),
+    spec("render:new_render_scheduling", Bool, "false", "Use triple buffering when needed."),
+    // cursor
+    spec("cursor:sync_gsettings_theme", Bool, "true", "Sync the cursor theme with gsettings."),
+    spec("cursor:no_hardware_cursors", Enum(ZERO_TO_TWO), "2", "Disable hardware cursors: 0 use them, 1 don't, 2 auto."),
+    spec("cursor:no_break_fs_vrr", Enum(ZERO_TO_TWO), "2", "Keep cursor movement from breaking VRR in fullscreen: 0 off, 1 on, 2 auto."),
+    spec("cursor:min_refresh_rate", Int, "24", "Minimum refresh rate for cursor movement with `no_break_fs_vrr`."),
+    spec("cursor:hotspot_padding", Int, "1", "Padding in logical pixels between the screen edges and the cursor."),
+    spec("cursor:inactive_timeout", Float, "0", "Seconds of inactivity before hiding the cursor, 0 to never hide it."),
+    spec("cursor:no_warps", Bool, "false", "Never warp the cursor, e.g. when changing focus with the keyboard."),
+    spec("cursor:persistent_warps", Bool, "false", "Return the cursor to its last position on a window when refocusing it."),
+    spec("cursor:warp_on_change_workspace", Enum(ZERO_TO_TWO), "0", "Move the cursor to the last focused window when changing workspaces."),
+    spec("cursor:warp_on_toggle_special", Enum(ZERO_TO_TWO), "0", "Move the cursor to the last focused window when toggling a special workspace."),
+    spec("cursor:default_monitor", Str, "", "The monitor to place the cursor on at startup."),
+    spec("cursor:zoom_factor", Float, "1.0", "The factor to zoom by around the cursor, at least 1.0."),
+    spec("cursor:zoom_rigid", Bool, "false", "Whether the zoom follows the cursor rigidly."),
+    spec("cursor:enable_hyprcursor", Bool, "true", "Whether to use hyprcursor themes."),
+    spec("cursor:hide_on_key_press", Bool, "false", "Hide the cursor when a key is pressed until the mouse moves."),
+    spec("cursor:hide_on_touch", Bool, "true", "Hide the cursor on touch input until the mouse moves."),
+    spec("cursor:use_cpu_buffer", Enum(ZERO_TO_TWO), "2", "Render hardware cursors through a CPU buffer: 0 off, 1 on, 2 auto."),
+    spec("cursor:warp_back_after_non_mouse_input", Bool, "false", "Warp the cursor back to where it was after non-mouse input."),
+    // ecosystem
+    spec("ecosystem:no_update_news", Bool, "false", "Disable the popup shown after updating."),
+    spec("ecosystem:no_donation_nag", Bool, "false", "Disable the occasional donation popup."),
+    spec("ecosystem:enforce_permissions", Bool, "false", "Whether permission control is enforced."),
+    // debug
+    spec("debug:overlay", Bool, "false", "Print the debug performance overlay."),
+    spec("debug:damage_blink", Bool, "false", "Flash areas updated with damage tracking."),
+    spec("debug:disable_logs", Bool, "true", "Disable logging to a file."),
+    spec("debug:disable_time", Bool, "true", "Disable time logging."),
+    spec("debug:damage_tracking", Enum(ZERO_TO_TWO), "2", "Redraw only the needed bits: 0 none, 1 monitor, 2 full."),
+    spec("debug:enable_stdout_logs", Bool, "false", "Enables logging to stdout."),
+    spec("debug:manual_crash", Int, "0", "Set to 1 and then back to 0 to crash Hyprland."),
+    spec("debug:suppress_errors", Bool, "false", "Whether to hide config file parsing errors."),
+    spec("debug:watchdog_timeout", Int, "5", "Seconds a frame may take before the watchdog kills Hyprland, 0 to disable."),
+    spec("debug:disable_scale_checks", Bool, "false", "Allow monitor scales that don't divide the resolution evenly."),
+    spec("debug:error_limit", Int, "5", "The maximum number of config errors shown."),
+    spec("debug:error_position", Enum(ZERO_OR_ONE), "0", "Show config errors at the top (0) or the bottom (1)."),
+    spec("debug:colored_stdout_logs", Bool, "true", "Whether stdout logs are colored."),
+    spec("debug:pass", Bool, "false", "Enables render pass debugging."),
+    spec("debug:full_cm_proto", Bool, "false", "Claim support for the full color management protocol."),
+    // dwindle
+    spec("dwindle:pseudotile", Bool, "false", "Enable pseudotiling: windows keep their floating size when tiled."),
+    spec("dwindle:force_split", Enum(ZERO_TO_TWO), "0", "Where new windows go: 0 follows the mouse, 1 left or top, 2 right or bottom."),
+    spec("dwindle:preserve_split", Bool, "false", "Keep the split direction regardless of the container size."),
+    spec("dwindle:smart_split", Bool, "false", "Split based on the cursor position within the window."),
+    spec("dwindle:smart_resizing", Bool, "true", "Resize in the direction of the cursor."),
+    spec("dwindle:permanent_direction_override", Bool, "false", "Keep a preselected direction until changed."),
+    spec("dwindle:special_scale_factor", Float, "1", "The scale of special workspace windows, from 0.0 to 1.0."),
+    spec("dwindle:split_width_multiplier", Float, "1.0", "Auto split width multiplier, for ultrawide monitors."),
+    spec("dwindle:use_active_for_splits", Bool, "true", "Prefer the active window over the mouse when splitting."),
+    spec("dwindle:default_split_ratio", Float, "1.0", "The default split ratio on window open, from 0.1 to 1.9."),
+    spec("dwindle:split_bias", Enum(ZERO_OR_ONE), "0", "Which window gets the larger share with a non-default split ratio: 0 directional, 1 current."),
+    spec("dwindle:precise_mouse_move", Bool, "false", "Drop windows more precisely with `bindm movewindow`."),
+    // master
+    spec("master:allow_small_split", Bool, "false", "Enable adding additional master windows in a horizontal split."),
+    spec("master:special_scale_factor", Float, "1", "The scale of special workspace windows, from 0.0 to 1.0."),
+    spec("master:mfact", Float, "0.55", "The size of the master area as a fraction of the screen."),
+    spec("master:new_status", Enum(&["master", "slave", "inherit"]), "slave", "Whether new windows become masters, slaves or inherit the status of the focused window."),
+    spec("master:new_on_top", Bool, "false", "Whether new windows go to the top of the stack."),
+    spec("master:new_on_active", Enum(&["before", "after", "none"]), "none", "Place new windows relative to the focused window."),
+    spec("master:orientation", Enum(&["left", "right", "top", "bottom", "center"]), "left", "The default placement of the master area."),
+    spec("master:inherit_fullscreen", Bool, "true", "Inherit fullscreen status when cycling or swapping."),
+    spec("master:slave_count_for_center_master", Int, "2", "The number of slaves needed to center the master with `orientation = center`."),
+    spec("master:center_master_fallback", Enum(&["left", "right", "top", "bottom"]), "left", "Where to put the master with `orientation = center` and too few slaves."),
+    spec("master:smart_resizing", Bool, "true", "Resize in the direction of the cursor."),
+    spec("master:drop_at_cursor", Bool, "true", "Drop windows dragged in a workspace at the cursor position."),
+    spec("master:always_keep_position", Bool, "false", "Keep the master window in its configured position when there are no slaves."),
+];
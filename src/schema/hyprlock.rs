@@ -0,0 +1,141 @@
+//! Categories and options of `hyprlock.conf`.
+//!
+//! REF
+//! - <https://wiki.hyprland.org/Hypr-Ecosystem/hyprlock/>
+
+use super::Type::{Bool, Color, Enum, Float, Gradient, Int, Keyword, Str};
+use super::{Schema, Spec, spec};
+
+const HALIGN: &[&str] = &["left", "center", "right", "none"];
+const VALIGN: &[&str] = &["top", "center", "bottom", "none"];
+
+pub static HYPRLOCK: Schema = Schema {
+    specs: SPECS,
+    open_categories: &[],
+    aliases: &[],
+};
+
+#[rustfmt::skip]
+const SPECS: &[Spec] = &[
+    spec("source", Keyword, "", "Include another config file."),
+    // general
+    spec("general:hide_cursor", Bool, "false", "Hide the cursor instead of making it visible."),
+    spec("general:grace", Int, "0", "Seconds after locking during which any input unlocks."),
+    spec("general:ignore_empty_input", Bool, "false", "Skip validation when no password is given."),
+    spec("general:immediate_render", Bool, "false", "Render immediately, without waiting for the screencopy."),
+    spec("general:text_trim", Bool, "true", "Trim surrounding whitespace from label text."),
+    spec("general:fractional_scaling", Enum(&["0", "1", "2"]), "2", "Whether to use fractional scaling: 0 no, 1 yes, 2 auto."),
+    spec("general:screencopy_mode", Enum(&["0", "1"]), "0", "Screencopy with the GPU (0) or the CPU (1)."),
+    spec("general:fail_timeout", Int, "2000", "Milliseconds before the fail state is cleared."),
+    // auth
+    spec("auth:pam:enabled", Bool, "true", "Whether to authenticate with PAM."),
+    spec("auth:pam:module", Str, "hyprlock", "The PAM module to use."),
+    spec("auth:fingerprint:enabled", Bool, "false", "Whether to authenticate with a fingerprint reader."),
+    spec("auth:fingerprint:ready_message", Str, "(Scan fingerprint to unlock)", "Message shown when the reader is ready."),
+    spec("auth:fingerprint:present_message", Str, "Scanning fingerprint", "Message shown while a finger is on the reader."),
+    spec("auth:fingerprint:retry_delay", Int, "250", "Milliseconds before retrying after a failed scan."),
+    // animations
+    spec("animations:enabled", Bool, "true", "Enable animations."),
+    spec("animations:bezier", Keyword, "", "Define a bezier curve: `name, X0, Y0, X1, Y1`."),
+    spec("animations:animation", Keyword, "", "Configure an animation: `name, onoff, speed, curve`."),
+    // background
+    spec("background:monitor", Str, "", "The monitor to draw on, empty for all of them."),
+    spec("background:path", Str, "", "Path to an image, or `screenshot`."),
+    spec("background:color", Color, "rgba(17, 17, 17, 1.0)", "Color shown when there is no image."),
+    spec("background:blur_passes", Int, "0", "Blur passes, 0 to disable blurring."),
+    spec("background:blur_size", Int, "7", "Blur size."),
+    spec("background:noise", Float, "0.0117", "Blur noise."),
+    spec("background:contrast", Float, "0.8917", "Blur contrast."),
+    spec("background:brightness", Float, "0.8172", "Blur brightness."),
+    spec("background:vibrancy", Float, "0.1686", "Blur vibrancy."),
+    spec("background:vibrancy_darkness", Float, "0.05", "Blur vibrancy darkness."),
+    spec("background:zindex", Int, "-1", "The order to draw widgets in, lower first."),
+    spec("background:reload_time", Int, "-1", "Seconds between reloading the image, -1 to never reload it."),
+    spec("background:reload_cmd", Str, "", "Command whose output is the path of the image to reload."),
+    spec("background:crossfade_time", Float, "-1.0", "Seconds to crossfade between images on reload, negative to disable."),
+    // shape
+    spec("shape:size", Str, "100, 100", "Size of the shape."),
+    spec("shape:color", Color, "rgba(17, 17, 17, 1.0)", "Fill color."),
+    spec("shape:rounding", Int, "0", "Corner rounding, -1 for a circle."),
+    spec("shape:border_size", Int, "0", "Border size."),
+    spec("shape:border_color", Gradient, "rgba(0, 207, 230, 1.0)", "Border color."),
+    spec("shape:rotate", Float, "0", "Rotation in degrees."),
+    spec("shape:xray", Bool, "false", "Make a transparent hole in the lock screen the size of the shape."),
+    spec("shape:monitor", Str, "", "The monitor to draw on, empty for all of them."),
+    spec("shape:position", Str, "0, 0", "Offset from the alignment point, in pixels or percent."),
+    spec("shape:halign", Enum(HALIGN), "center", "Horizontal alignment."),
+    spec("shape:valign", Enum(VALIGN), "center", "Vertical alignment."),
+    spec("shape:zindex", Int, "0", "The order to draw widgets in, lower first."),
+    spec("shape:shadow_passes", Int, "0", "Shadow passes, 0 to disable the shadow."),
+    spec("shape:shadow_size", Int, "3", "Shadow size."),
+    spec("shape:shadow_color", Color, "rgb(0,0,0)", "Shadow color."),
+    spec("shape:shadow_boost", Float, "1.2", "Boost the shadow's opacity."),
+    // image
+    spec("image:path", Str, "", "Path to the image."),
+    spec("image:size", Int, "150", "Size of the lesser side of the image."),
+    spec("image:rounding", Int, "-1", "Corner rounding, -1 for a circle."),
+    spec("image:border_size", Int, "4", "Border size."),
+    spec("image:border_color", Gradient, "rgba(221, 221, 221, 1.0)", "Border color."),
+    spec("image:rotate", Float, "0", "Rotation in degrees."),
+    spec("image:reload_time", Int, "-1", "Seconds between reloading the image, -1 to never reload it."),
+    spec("image:reload_cmd", Str, "", "Command whose output is the path of the image to reload."),
+    spec("image:monitor", Str, "", "The monitor to draw on, empty for all of them."),
+    spec("image:position", Str, "0, 0", "Offset from the alignment point, in pixels or percent."),
+    spec("image:halign", Enum(HALIGN), "center", "Horizontal alignment."),
+    spec("image:valign", Enum(VALIGN), "center", "Vertical alignment."),
+    spec("image:zindex", Int, "0", "The order to draw widgets in, lower first."),
+    spec("image:shadow_passes", Int, "0", "Shadow passes, 0 to disable the shadow."),
+    spec("image:shadow_size", Int, "3", "Shadow size."),
+    spec("image:shadow_color", Color, "rgb(0,0,0)", "Shadow color."),
+    spec("image:shadow_boost", Float, "1.2", "Boost the shadow's opacity."),
+    // input-field
+    spec("input-field:size", Str, "400, 90", "Size of the input field."),
+    spec("input-field:outline_thickness", Int, "4", "Thickness of the outline."),
+    spec("input-field:dots_size", Float, "0.25", "Size of the dots, relative to the field height, from 0.2 to 0.8."),
+    spec("input-field:dots_spacing", Float, "0.15", "Spacing between the dots, relative to their size."),
+    spec("input-field:dots_center", Bool, "true", "Whether to center the dots."),
+    spec("input-field:dots_rounding", Int, "-1", "Rounding of the dots, -1 for circles."),
+    spec("input-field:dots_text_format", Str, "", "Text to show instead of dots."),
+    spec("input-field:outer_color", Gradient, "rgba(17, 17, 17, 1.0)", "Color of the outline."),
+    spec("input-field:inner_color", Color, "rgba(200, 200, 200, 1.0)", "Color of the field."),
+    spec("input-field:font_color", Color, "rgba(10, 10, 10, 1.0)", "Color of the dots and text."),
+    spec("input-field:font_family", Str, "Noto Sans", "Font of the placeholder and fail text."),
+    spec("input-field:fade_on_empty", Bool, "true", "Fade the field out when it is empty."),
+    spec("input-field:fade_timeout", Int, "2000", "Milliseconds before fading out an empty field."),
+    spec("input-field:placeholder_text", Str, "<i>Input Password...</i>", "Text shown when the field is empty."),
+    spec("input-field:hide_input", Bool, "false", "Show a random color instead of dots."),
+    spec("input-field:rounding", Int, "-1", "Corner rounding, -1 for full rounding."),
+    spec("input-field:check_color", Gradient, "rgba(204, 136, 34, 1.0)", "Outline color while checking the password."),
+    spec("input-field:fail_color", Gradient, "rgba(204, 34, 34, 1.0)", "Outline color when authentication fails."),
+    spec("input-field:fail_text", Str, "<i>$FAIL</i>", "Text shown when authentication fails."),
+    spec("input-field:capslock_color", Gradient, "", "Outline color when caps lock is on."),
+    spec("input-field:numlock_color", Gradient, "", "Outline color when num lock is on."),
+    spec("input-field:bothlock_color", Gradient, "", "Outline color when both caps and num lock are on."),
+    spec("input-field:invert_numlock", Bool, "false", "Change the color when num lock is off instead of on."),
+    spec("input-field:swap_font_color", Bool, "false", "Use the outline color for the text when a lock is on."),
+    spec("input-field:monitor", Str, "", "The monitor to draw on, empty for all of them."),
+    spec("input-field:position", Str, "0, 0", "Offset from the alignment point, in pixels or percent."),
+    spec("input-field:halign", Enum(HALIGN), "center", "Horizontal alignment."),
+    spec("input-field:valign", Enum(VALIGN), "center", "Vertical alignment."),
+    spec("input-field:zindex", Int, "0", "The order to draw widgets in, lower first."),
+    spec("input-field:shadow_passes", Int, "0", "Shadow passes, 0 to disable the shadow."),
+    spec("input-field:shadow_size", Int, "3", "Shadow size."),
+    spec("input-field:shadow_color", Color, "rgb(0,0,0)", "Shadow color."),
+    spec("input-field:shadow_boost", Float, "1.2", "Boost the shadow's opacity."),
+    // label
+    spec("label:text", Str, "Sample Text", "The text, which may use Pango markup and `cmd[update:ms]` commands."),
+    spec("label:text_align", Str, "", "Alignment of multi-line text: `left`, `center` or `right`."),
+    spec("label:color", Color, "rgba(254, 254, 254, 1.0)", "Text color."),
+    spec("label:font_size", Int, "16", "Font size."),
+    spec("label:font_family", Str, "Sans", "Font family."),
+    spec("label:rotate", Float, "0", "Rotation in degrees."),
+    spec("label:monitor", Str, "", "The monitor to draw on, empty for all of them."),
+    spec("label:position", Str, "0, 0", "Offset from the alignment point, in pixels or percent."),
+    spec("label:halign", Enum(HALIGN), "center", "Horizontal alignment."),
+    spec("label:valign", Enum(VALIGN), "center", "Vertical alignment."),
+    spec("label:zindex", Int, "0", "The order to draw widgets in, lower first."),
+    spec("label:shadow_passes", Int, "0", "Shadow passes, 0 to disable the shadow."),
+    spec("label:shadow_size", Int, "3", "Shadow size."),
+    spec("label:shadow_color", Color, "rgb(0,0,0)", "Shadow color."),
+    spec("label:shadow_boost", Float, "1.2", "Boost the shadow's opacity."),
+];
@@ -0,0 +1,28 @@
+//! Keywords and options of `hyprpaper.conf`.
+//!
+//! REF
+//! - <https://wiki.hyprland.org/Hypr-Ecosystem/hyprpaper/>
+
+use super::Type::{Bool, Color, Float, Keyword, Str};
+use super::{Schema, Spec, spec};
+
+pub static HYPRPAPER: Schema = Schema {
+    specs: SPECS,
+    open_categories: &[],
+    aliases: &[],
+};
+
+#[rustfmt::skip]
+const SPECS: &[Spec] = &[
+    spec("source", Keyword, "", "Include another config file."),
+    spec("preload", Keyword, "", "Load an image into memory: `path`."),
+    spec("unload", Keyword, "", "Unload a preloaded image, or `all`."),
+    spec("wallpaper", Keyword, "", "Set a preloaded image as a monitor's wallpaper: `monitor, path`."),
+    spec("wallpaper:monitor", Str, "", "The monitor to set the wallpaper on, empty for all of them."),
+    spec("wallpaper:path", Str, "", "The image to show."),
+    spec("wallpaper:fit_mode", Str, "cover", "How to fit the image: `contain`, `cover`, `tile` or `fill`."),
+    spec("splash", Bool, "false", "Render the Hyprland splash over the wallpaper."),
+    spec("splash_offset", Float, "2.0", "How far up the splash is rendered, in percent of the monitor height."),
+    spec("splash_color", Color, "55ffffff", "The color of the splash text."),
+    spec("ipc", Bool, "true", "Whether to enable IPC."),
+];
@@ -0,0 +1,414 @@
+mod hypridle;
+mod hyprland;
+mod hyprlock;
+mod hyprpaper;
+mod xdph;
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::path::Path;
+
+pub use hypridle::HYPRIDLE;
+pub use hyprland::HYPRLAND;
+pub use hyprlock::HYPRLOCK;
+pub use hyprpaper::HYPRPAPER;
+pub use xdph::XDPH;
+
+/// The program a config is for, each with its own categories and options.
+#[derive(clap::ValueEnum, PartialEq, Clone, Copy, Debug, Default)]
+pub enum Dialect {
+    #[default]
+    Hyprland,
+    Hypridle,
+    Hyprlock,
+    Hyprpaper,
+    Xdph,
+}
+
+impl Dialect {
+    pub fn schema(self) -> &'static Schema {
+        match self {
+            Dialect::Hyprland => &HYPRLAND,
+            Dialect::Hypridle => &HYPRIDLE,
+            Dialect::Hyprlock => &HYPRLOCK,
+            Dialect::Hyprpaper => &HYPRPAPER,
+            Dialect::Xdph => &XDPH,
+        }
+    }
+
+    /// The dialect of a file named like the program's default config, e.g. `hyprlock.conf`.
+    pub fn detect(path: &Path) -> Option<Self> {
+        match path.file_stem()?.to_str()? {
+            "hyprland" => Some(Dialect::Hyprland),
+            "hypridle" => Some(Dialect::Hypridle),
+            "hyprlock" => Some(Dialect::Hyprlock),
+            "hyprpaper" => Some(Dialect::Hyprpaper),
+            "xdph" => Some(Dialect::Xdph),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Dialect {
+    fn fmt(
+        &self,
+        formatter: &mut std::fmt::Formatter<'_>,
+    ) -> std::result::Result<(), std::fmt::Error> {
+        formatter.write_str(match self {
+            Self::Hyprland => "hyprland",
+            Self::Hypridle => "hypridle",
+            Self::Hyprlock => "hyprlock",
+            Self::Hyprpaper => "hyprpaper",
+            Self::Xdph => "xdph",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Type {
+    Int,
+    Float,
+    Bool,
+    Color,
+    Vec2,
+    /// One to four ints, like CSS margins.
+    Gaps,
+    Str,
+    Gradient,
+    Enum(&'static [&'static str]),
+    /// A free-form keyword that may be given any number of times, like `bind` or `monitor`.
+    Keyword,
+}
+
+impl Type {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Type::Int => "int",
+            Type::Float => "float",
+            Type::Bool => "bool",
+            Type::Color => "color",
+            Type::Vec2 => "vec2",
+            Type::Gaps => "gaps",
+            Type::Str => "str",
+            Type::Gradient => "gradient",
+            Type::Enum(_) => "enum",
+            Type::Keyword => "keyword",
+        }
+    }
+
+    /// Whether Hyprland would accept `value` for an option of this type.
+    pub fn accepts(&self, value: &str) -> bool {
+        let value = value.trim();
+
+        match self {
+            Type::Int => is_int(value) || is_bool(value),
+            Type::Float => is_float(value),
+            Type::Bool => is_bool(value) || is_int(value),
+            Type::Color => is_color(value),
+            Type::Vec2 => {
+                let parts = split_numbers(value);
+                parts.len() == 2 && parts.iter().all(|part| is_float(part))
+            }
+            Type::Gaps => {
+                let parts = split_numbers(value);
+                (1..=4).contains(&parts.len()) && parts.iter().all(|part| is_int(part))
+            }
+            Type::Gradient => {
+                let mut parts = split_outside_parens(value);
+                if parts
+                    .last()
+                    .and_then(|part| part.strip_suffix("deg"))
+                    .is_some_and(is_float)
+                {
+                    parts.pop();
+                }
+
+                !parts.is_empty() && parts.into_iter().all(is_color)
+            }
+            Type::Enum(values) => values.contains(&value),
+            Type::Str | Type::Keyword => true,
+        }
+    }
+}
+
+fn is_int(value: &str) -> bool {
+    let value = value.strip_prefix(['-', '+']).unwrap_or(value);
+
+    match value.strip_prefix("0x") {
+        Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        None => !value.is_empty() && value.chars().all(|c| c.is_ascii_digit()),
+    }
+}
+
+fn is_float(value: &str) -> bool {
+    value.parse::<f64>().is_ok()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E'))
+}
+
+fn is_bool(value: &str) -> bool {
+    matches!(value, "true" | "false" | "yes" | "no" | "on" | "off")
+}
+
+/// `0xAARRGGBB`, `rgba(RRGGBBAA)`, `rgb(RRGGBB)`, `rgba(r, g, b, a)` or `rgb(r, g, b)`.
+fn is_color(value: &str) -> bool {
+    let function =
+        [("rgba(", 8, 4), ("rgb(", 6, 3)]
+            .into_iter()
+            .find_map(|(prefix, hex_len, components)| {
+                let inner = value.strip_prefix(prefix)?.strip_suffix(')')?;
+                Some((inner.trim(), hex_len, components))
+            });
+
+    let Some((inner, hex_len, components)) = function else {
+        return is_int(value);
+    };
+
+    if inner.contains(',') {
+        let parts = inner.split(',').map(str::trim).collect::<Vec<_>>();
+        parts.len() == components && parts.into_iter().all(is_float)
+    } else {
+        inner.len() == hex_len && inner.chars().all(|c| c.is_ascii_hexdigit())
+    }
+}
+
+/// Numbers separated by commas and/or spaces.
+fn split_numbers(value: &str) -> Vec<&str> {
+    value
+        .split([',', ' ', '\t'])
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// Whitespace separated words, where whitespace inside parentheses does not separate.
+fn split_outside_parens(value: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0_usize;
+    let mut start = None;
+
+    for (idx, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(start) = start.take() {
+                    parts.push(&value[start..idx]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        start.get_or_insert(idx);
+    }
+
+    parts.extend(start.map(|start| &value[start..]));
+
+    parts
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Type::Enum(values) => write!(f, "one of {}", values.join(", ")),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+/// A single option or keyword, e.g. `general:gaps_in`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spec {
+    /// The full colon separated path.
+    pub path: &'static str,
+    pub ty: Type,
+    pub default: &'static str,
+    pub description: &'static str,
+}
+
+impl Spec {
+    /// The last component of the path.
+    pub fn name(&self) -> &'static str {
+        self.path.rsplit(':').next().unwrap_or(self.path)
+    }
+
+    /// Markdown documentation, as shown on hover.
+    pub fn documentation(&self) -> String {
+        let default = match self.default {
+            "" => String::new(),
+            default => format!(" (default `{default}`)"),
+        };
+
+        format!(
+            "**{}** `{}`{default}\n\n{}",
+            self.path, self.ty, self.description
+        )
+    }
+}
+
+pub(crate) const fn spec(
+    path: &'static str,
+    ty: Type,
+    default: &'static str,
+    description: &'static str,
+) -> Spec {
+    Spec {
+        path,
+        ty,
+        default,
+        description,
+    }
+}
+
+/// The categories, options and keywords understood by a program reading hyprlang.
+#[derive(Debug)]
+pub struct Schema {
+    pub specs: &'static [Spec],
+    /// Categories whose contents are not known, such as plugin settings.
+    pub open_categories: &'static [&'static str],
+    /// Categories that accept the options of other categories, e.g. `device` takes `input`
+    /// options.
+    pub aliases: &'static [(&'static str, &'static str)],
+}
+
+impl Schema {
+    /// The spec for the full colon separated `path`.
+    pub fn get(&self, path: &str) -> Option<&Spec> {
+        self.specs
+            .iter()
+            .find(|spec| spec.path == path)
+            .or_else(|| {
+                self.aliases.iter().find_map(|(alias, target)| {
+                    let rest = path.strip_prefix(alias)?.strip_prefix(':')?;
+                    self.get(&format!("{target}:{rest}"))
+                })
+            })
+    }
+
+    /// Every category, including nested ones like `decoration:blur`.
+    pub fn categories(&self) -> BTreeSet<&'static str> {
+        self.specs
+            .iter()
+            .flat_map(|spec| {
+                spec.path
+                    .match_indices(':')
+                    .map(|(idx, _)| &spec.path[..idx])
+            })
+            .chain(self.open_categories.iter().copied())
+            .chain(self.aliases.iter().map(|(alias, _)| *alias))
+            .collect()
+    }
+
+    pub fn is_category(&self, path: &str) -> bool {
+        self.categories().contains(path)
+    }
+
+    /// Whether anything may be set inside the category at `path`.
+    pub fn is_open(&self, path: &str) -> bool {
+        self.open_categories.iter().any(|category| {
+            path == *category
+                || path
+                    .strip_prefix(category)
+                    .is_some_and(|rest| rest.starts_with(':'))
+        })
+    }
+
+    /// The specs that can be set from inside `category`, with `""` being the top level.
+    pub fn specs_in<'a>(&'a self, category: &'a str) -> impl Iterator<Item = (String, &'a Spec)> {
+        let targets = self
+            .aliases
+            .iter()
+            .filter(move |(alias, _)| *alias == category)
+            .map(|(_, target)| *target)
+            .chain([category]);
+
+        targets.flat_map(move |target| {
+            self.specs.iter().filter_map(move |spec| {
+                let relative = match target {
+                    "" => spec.path,
+                    _ => spec.path.strip_prefix(target)?.strip_prefix(':')?,
+                };
+
+                Some((relative.to_string(), spec))
+            })
+        })
+    }
+}
+
+/// The colon separated path of a key set inside the nested `categories`, without device
+/// selectors like the `[mouse]` in `device[mouse]:sensitivity`.
+pub fn path(categories: &[&str], key: &str) -> String {
+    categories
+        .iter()
+        .copied()
+        .chain([key])
+        .flat_map(|part| part.split(':'))
+        .map(|part| part.split_once('[').map_or(part, |(name, _)| name))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let spec = HYPRLAND.get("decoration:blur:enabled").unwrap();
+        assert_eq!(spec.ty, Type::Bool);
+        assert_eq!(spec.name(), "enabled");
+
+        assert_eq!(
+            HYPRLAND.get("device:sensitivity").map(|spec| spec.path),
+            Some("input:sensitivity")
+        );
+        assert!(HYPRLAND.get("general:gaps").is_none());
+
+        assert_eq!(
+            path(&["input"], "device[mouse]:enabled"),
+            "input:device:enabled"
+        );
+        assert!(HYPRLAND.is_category("decoration:blur"));
+        assert!(HYPRLAND.is_open("plugin:hyprbars"));
+        assert!(
+            HYPRLAND
+                .specs_in("decoration")
+                .any(|(relative, _)| relative == "blur:size")
+        );
+    }
+
+    #[test]
+    fn test_dialects() {
+        assert_eq!(
+            Dialect::detect(Path::new("/home/me/.config/hypr/hypridle.conf")),
+            Some(Dialect::Hypridle)
+        );
+        assert_eq!(Dialect::detect(Path::new("colors.conf")), None);
+
+        assert!(Dialect::Hypridle.schema().is_category("listener"));
+        assert!(Dialect::Hyprlock.schema().is_category("input-field"));
+        assert!(!HYPRLAND.is_category("listener"));
+        assert!(!HYPRLAND.is_category("background"));
+    }
+
+    #[test]
+    fn test_accepts() {
+        assert!(Type::Int.accepts("-10"));
+        assert!(!Type::Int.accepts("abc"));
+        assert!(Type::Bool.accepts("yes"));
+        assert!(!Type::Bool.accepts("maybe"));
+        assert!(Type::Float.accepts("0.5"));
+        assert!(Type::Gaps.accepts("5,10, 5 10"));
+        assert!(!Type::Gaps.accepts("1 2 3 4 5"));
+        assert!(Type::Vec2.accepts("0, 0"));
+        assert!(Type::Color.accepts("rgba(1a1a1aee)"));
+        assert!(Type::Color.accepts("rgba(26, 26, 26, 0.9)"));
+        assert!(!Type::Color.accepts("rgb(1a1a1aee)"));
+        assert!(Type::Gradient.accepts("rgba(33ccffee) rgba(00ff99ee) 45deg"));
+        assert!(Type::Gradient.accepts("0xff444444"));
+        assert!(!Type::Gradient.accepts("45deg"));
+        assert!(Type::Enum(&["master", "slave"]).accepts("master"));
+        assert!(!Type::Enum(&["master", "slave"]).accepts("Master"));
+    }
+}
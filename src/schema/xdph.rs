@@ -0,0 +1,21 @@
+//! Categories and options of `xdph.conf`, for xdg-desktop-portal-hyprland.
+//!
+//! REF
+//! - <https://wiki.hyprland.org/Hypr-Ecosystem/xdg-desktop-portal-hyprland/>
+
+use super::Type::{Bool, Int, Keyword, Str};
+use super::{Schema, Spec, spec};
+
+pub static XDPH: Schema = Schema {
+    specs: SPECS,
+    open_categories: &[],
+    aliases: &[],
+};
+
+#[rustfmt::skip]
+const SPECS: &[Spec] = &[
+    spec("source", Keyword, "", "Include another config file."),
+    spec("screencopy:max_fps", Int, "120", "Maximum fps of a screencast, 0 for no limit."),
+    spec("screencopy:allow_token_by_default", Bool, "false", "Check the \"allow a restore token\" box in the picker by default."),
+    spec("screencopy:custom_picker_binary", Str, "", "A binary to use instead of the default share picker."),
+];
@@ -0,0 +1,182 @@
+use std::fmt;
+
+use crate::dump::{Setting, Settings};
+use crate::parsed::bind::{bind_flags, canonical_mods};
+
+/// Keywords that Hyprland applies in order, so reordering their values changes the config.
+const ORDERED: &[&str] = &[
+    "exec",
+    "exec-once",
+    "execr",
+    "execr-once",
+    "exec-shutdown",
+    "windowrule",
+    "windowrulev2",
+    "layerrule",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change<'a> {
+    Added(&'a str, String),
+    Removed(&'a str, String),
+    /// An option set to another value, from the old to the new one.
+    Changed(&'a str, &'a str, &'a str),
+    /// A keyword given the same values in another order.
+    Reordered(&'a str),
+}
+
+impl fmt::Display for Change<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Added(key, value) => write!(f, "+ {key} = {value}"),
+            Self::Removed(key, value) => write!(f, "- {key} = {value}"),
+            Self::Changed(key, old, new) => write!(f, "~ {key} = {old} -> {new}"),
+            Self::Reordered(key) => write!(f, "~ {key} reordered"),
+        }
+    }
+}
+
+/// `value` with what Hyprland ignores removed: the spacing around the mods, key and dispatcher of
+/// a bind, and the order and case of its mods. The arguments are kept as they are, as they can be
+/// commands.
+pub fn normalize(key: &str, value: &str) -> String {
+    if bind_flags(key).is_none() {
+        return value.to_string();
+    }
+
+    normalize_bind(value, |mods| canonical_mods(mods).join(" "))
+}
+
+/// A bind `value` without the spacing around its mods, key and dispatcher, and with its mods
+/// replaced by what `mods` makes of them.
+pub fn normalize_bind(value: &str, mods: impl FnOnce(&str) -> String) -> String {
+    let mut fields = value.splitn(4, ',').map(str::trim);
+    let mods = mods(fields.next().unwrap_or_default());
+
+    std::iter::once(mods.as_str())
+        .chain(fields)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn values(
+    key: &str,
+    setting: Option<&Setting>,
+    normalize: fn(&str, &str) -> String,
+) -> Vec<String> {
+    let values = match setting {
+        Some(Setting::Value(value)) => std::slice::from_ref(value),
+        Some(Setting::List(values)) => values,
+        None => &[],
+    };
+
+    values.iter().map(|value| normalize(key, value)).collect()
+}
+
+/// The values of `a` that are not in `b`, counting repeated values.
+fn missing(a: &[String], b: &[String]) -> Vec<String> {
+    let mut unmatched = b.iter().collect::<Vec<_>>();
+
+    a.iter()
+        .filter(
+            |value| match unmatched.iter().position(|other| other == value) {
+                Some(idx) => {
+                    unmatched.swap_remove(idx);
+                    false
+                }
+                None => true,
+            },
+        )
+        .cloned()
+        .collect()
+}
+
+/// What changed from the `old` settings to the `new` ones, by key.
+///
+/// The values of keywords are compared regardless of their order, except for keywords Hyprland
+/// applies in order.
+pub fn semdiff<'a>(old: &'a Settings, new: &'a Settings) -> Vec<Change<'a>> {
+    semdiff_with(old, new, normalize)
+}
+
+/// Like [`semdiff`], with the values of keywords normalized by `normalize` instead of
+/// [`normalize`].
+pub fn semdiff_with<'a>(
+    old: &'a Settings,
+    new: &'a Settings,
+    normalize: fn(&str, &str) -> String,
+) -> Vec<Change<'a>> {
+    let mut keys = old.keys().chain(new.keys()).collect::<Vec<_>>();
+    keys.sort();
+    keys.dedup();
+
+    let mut changes = vec![];
+
+    for key in keys {
+        match (old.get(key), new.get(key)) {
+            (Some(Setting::Value(old)), Some(Setting::Value(new))) => {
+                if old != new {
+                    changes.push(Change::Changed(key, old, new));
+                }
+            }
+            (old, new) => {
+                let old = values(key, old, normalize);
+                let new = values(key, new, normalize);
+
+                let removed = missing(&old, &new);
+                let added = missing(&new, &old);
+
+                if removed.is_empty() && added.is_empty() && old != new {
+                    if ORDERED.contains(&key.as_str()) {
+                        changes.push(Change::Reordered(key));
+                    }
+                    continue;
+                }
+
+                changes.extend(removed.into_iter().map(|value| Change::Removed(key, value)));
+                changes.extend(added.into_iter().map(|value| Change::Added(key, value)));
+            }
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dump::settings;
+    use crate::schema::HYPRLAND;
+    use crate::sources::{Source, Sources};
+
+    fn resolve(text: &str) -> Settings {
+        let sources = Sources::load(Source::new(None, text.to_string()));
+        let (statements, _) = sources.statements();
+
+        settings(&HYPRLAND, &statements)
+    }
+
+    #[test]
+    fn test_semdiff() {
+        let old = resolve(
+            "$gap = 5\ngeneral {\n  gaps_in = $gap\n  border_size = 2\n}\nbind = SUPER, Q, exec, kitty\nbind = SUPER, C, killactive\nexec-once = a\nexec-once = b\nbinds {\n  scroll_event_delay = 300\n}\n",
+        );
+        let new = resolve(
+            "# reformatted\ngeneral:gaps_in=5\nbind=SUPER,C,killactive\nbind = SUPER, Q, exec, kitty\nbind = SUPER, E, exec, dolphin\nexec-once = b\nexec-once = a\ngeneral:border_size = 3\nbinds:scroll_event_delay = 100\n",
+        );
+
+        assert_eq!(
+            semdiff(&old, &new)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "- $gap = 5",
+                "+ bind = SUPER, E, exec, dolphin",
+                "~ binds:scroll_event_delay = 300 -> 100",
+                "~ exec-once reordered",
+                "~ general:border_size = 2 -> 3",
+            ]
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::{env, fs, io};
+
+use crate::cst;
+use crate::diagnostic::{Diagnostic, Location, Position, Severity};
+use crate::parse::{ParseError, recover};
+use crate::parsed::line::{Line, get_lines};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Source {
+    pub path: Option<PathBuf>,
+    pub text: String,
+}
+
+impl Source {
+    pub fn new(path: Option<PathBuf>, text: String) -> Self {
+        Self { path, text }
+    }
+
+    pub fn read(path: &Path) -> io::Result<Self> {
+        Ok(Self::new(
+            Some(path.to_path_buf()),
+            fs::read_to_string(path)?,
+        ))
+    }
+
+    /// Byte offset of `slice` in the text, if `slice` borrows from it.
+    pub fn offset(&self, slice: &str) -> Option<usize> {
+        let start = (slice.as_ptr() as usize).checked_sub(self.text.as_ptr() as usize)?;
+
+        (start + slice.len() <= self.text.len()).then_some(start)
+    }
+
+    pub fn position(&self, offset: usize) -> Position {
+        let before = &self.text[..offset];
+
+        Position {
+            line: before.matches('\n').count() + 1,
+            column: before
+                .rsplit('\n')
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .count()
+                + 1,
+        }
+    }
+
+    /// The location from the start of `first` to the end of `last`, both borrowed from the text.
+    pub fn span(&self, first: &str, last: &str) -> Location {
+        let start = self.offset(first).unwrap_or_default();
+        let end = self
+            .offset(last)
+            .map_or(start, |offset| offset + last.len());
+
+        Location {
+            path: self.path.clone(),
+            start: self.position(start),
+            end: self.position(end),
+        }
+    }
+
+    pub fn location(&self, slice: &str) -> Location {
+        self.span(slice, slice)
+    }
+
+    pub fn lines(&self) -> Result<Rc<[Line<'_>]>, ParseError> {
+        cst::parse(&self.text).map(|tree| get_lines(&tree))
+    }
+
+    pub fn parse_error(&self, e: &ParseError) -> Diagnostic {
+        let (line, column) = e.line_col();
+        let position = Position { line, column };
+
+        Diagnostic::new(
+            "parse-error",
+            Severity::Error,
+            Location {
+                path: self.path.clone(),
+                start: position,
+                end: position,
+            },
+            e.message(),
+        )
+    }
+
+    /// A diagnostic for every line of this file that doesn't parse.
+    pub fn parse_errors(&self) -> Vec<Diagnostic> {
+        match recover(&self.text) {
+            Ok(recovered) => recovered
+                .invalid
+                .iter()
+                .map(|(_, e)| self.parse_error(e))
+                .collect(),
+            Err(e) => vec![self.parse_error(&e)],
+        }
+    }
+
+    /// Resolve the value of a `source = ...` line relative to this file.
+    pub fn resolve(&self, value: &str) -> PathBuf {
+        let path = match value.strip_prefix("~/") {
+            Some(rest) => env::var_os("HOME").map_or_else(
+                || PathBuf::from(value),
+                |home| PathBuf::from(home).join(rest),
+            ),
+            None => PathBuf::from(value),
+        };
+
+        let path = match self.path.as_ref().and_then(|path| path.parent()) {
+            Some(dir) if path.is_relative() => dir.join(path),
+            _ => path,
+        };
+
+        // Drops any `.` components
+        path.components().collect()
+    }
+}
+
+/// Returns the value of a top-level `source = ...` line.
+pub fn source_value<'a>(line: &Line<'a>) -> Option<&'a str> {
+    line.as_sectionable()
+        .filter(|info| info.indent == 0 && info.lhs == "source")
+        .and_then(|info| info.rhs)
+}
+
+#[derive(Debug, Clone)]
+pub struct Statement<'a> {
+    pub source: &'a Source,
+    pub line: Line<'a>,
+}
+
+/// A file along with every file it (transitively) pulls in with `source = ...`.
+#[derive(Debug, Default)]
+pub struct Sources {
+    pub files: Vec<Source>,
+    pub diagnostics: Vec<Diagnostic>,
+    index: HashMap<PathBuf, usize>,
+}
+
+impl Sources {
+    pub fn load(root: Source) -> Self {
+        Self::load_with(root, Source::read)
+    }
+
+    /// Like [`Sources::load`], but reads sourced files with `read`, e.g. to prefer unsaved
+    /// editor buffers over what is on disk.
+    pub fn load_with(root: Source, read: impl Fn(&Path) -> io::Result<Source>) -> Self {
+        let mut sources = Self::default();
+
+        sources.push(root);
+
+        let mut idx = 0;
+        while let Some(source) = sources.files.get(idx) {
+            let Ok(lines) = source.lines() else {
+                idx += 1;
+                continue;
+            };
+
+            let found = lines
+                .iter()
+                .filter_map(|line| source_value(line))
+                .map(|value| (source.resolve(value), source.location(value)))
+                .collect::<Vec<_>>();
+
+            for (path, location) in found {
+                if sources.index.contains_key(&canonical(&path)) {
+                    continue;
+                }
+
+                match read(&path) {
+                    Ok(source) => sources.push(source),
+                    Err(e) => sources.diagnostics.push(Diagnostic::new(
+                        "source-not-found",
+                        Severity::Warning,
+                        location,
+                        format!("cannot read sourced file {}: {e}", path.display()),
+                    )),
+                }
+            }
+
+            idx += 1;
+        }
+
+        sources
+    }
+
+    fn push(&mut self, source: Source) {
+        if let Some(path) = &source.path {
+            self.index.insert(canonical(path), self.files.len());
+        }
+
+        self.files.push(source);
+    }
+
+    pub fn root(&self) -> &Source {
+        &self.files[0]
+    }
+
+    /// Every line of every file, in the order Hyprland evaluates them: the lines of a sourced
+    /// file directly follow the `source = ...` line that pulls it in.
+    ///
+    /// Every line that doesn't parse is returned as a diagnostic, and the offending file is
+    /// skipped.
+    pub fn statements(&self) -> (Vec<Statement<'_>>, Vec<Diagnostic>) {
+        let mut diagnostics = vec![];
+
+        let lines = self
+            .files
+            .iter()
+            .map(|source| {
+                source
+                    .lines()
+                    .map_err(|_| diagnostics.extend(source.parse_errors()))
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+
+        let mut statements = vec![];
+        let mut visited = HashSet::new();
+
+        self.walk(0, &lines, &mut visited, &mut statements);
+
+        (statements, diagnostics)
+    }
+
+    fn walk<'a>(
+        &'a self,
+        idx: usize,
+        lines: &[Option<Rc<[Line<'a>]>>],
+        visited: &mut HashSet<usize>,
+        statements: &mut Vec<Statement<'a>>,
+    ) {
+        if !visited.insert(idx) {
+            return;
+        }
+
+        let source = &self.files[idx];
+        let Some(Some(file_lines)) = lines.get(idx) else {
+            return;
+        };
+
+        for line in file_lines.iter() {
+            statements.push(Statement {
+                source,
+                line: line.clone(),
+            });
+
+            let sourced = source_value(line)
+                .and_then(|value| self.index.get(&canonical(&source.resolve(value))));
+
+            if let Some(&sourced) = sourced {
+                self.walk(sourced, lines, visited, statements);
+            }
+        }
+    }
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
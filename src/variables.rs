@@ -0,0 +1,136 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::sources::{Source, Statement};
+
+/// `$variable` definitions, in the order they are encountered.
+#[derive(Debug, Default, Clone)]
+pub struct Variables {
+    values: HashMap<String, String>,
+}
+
+impl Variables {
+    /// Define `name` (with or without its leading `$`), substituting any variables in `value`
+    /// that are already defined, like Hyprland does.
+    pub fn define(&mut self, name: &str, value: &str) {
+        let value = self.substitute(value);
+
+        self.values
+            .insert(name.trim_start_matches('$').to_string(), value);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .get(name.trim_start_matches('$'))
+            .map(String::as_str)
+    }
+
+    /// The length of the longest defined variable name that `ident` starts with.
+    ///
+    /// Hyprland substitutes the longest matching name, so `$modSHIFT` expands `$mod` when only
+    /// `$mod` is defined.
+    pub fn longest_match(&self, ident: &str) -> Option<usize> {
+        longest_match(ident, |name| self.values.contains_key(name))
+    }
+
+    /// Replace every `$variable` in `text` with its value. Undefined variables are kept as-is.
+    pub fn substitute(&self, text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(idx) = rest.find('$') {
+            result.push_str(&rest[..idx]);
+            rest = &rest[idx + 1..];
+
+            let ident_len = rest
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len());
+
+            match self.longest_match(&rest[..ident_len]) {
+                Some(len) => {
+                    result.push_str(&self.values[&rest[..len]]);
+                    rest = &rest[len..];
+                }
+                None => result.push('$'),
+            }
+        }
+
+        result.push_str(rest);
+
+        result
+    }
+}
+
+/// The length of the longest prefix of `ident` for which `is_defined` holds.
+pub fn longest_match(ident: &str, is_defined: impl Fn(&str) -> bool) -> Option<usize> {
+    (1..=ident.len())
+        .rev()
+        .find(|&len| ident.is_char_boundary(len) && is_defined(&ident[..len]))
+}
+
+/// Every `$ident` in `text`, as slices of `text`.
+pub fn references(text: &str) -> Vec<&str> {
+    text.match_indices('$')
+        .map(|(idx, _)| {
+            let rest = &text[idx + 1..];
+            let len = rest
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len());
+
+            &text[idx..=idx + len]
+        })
+        .filter(|reference| reference.len() > 1)
+        .collect()
+}
+
+/// A definition of, or reference to, a variable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occurrence<'a> {
+    pub source: &'a Source,
+    /// The name, without its leading `$`.
+    pub name: &'a str,
+    /// The `$name` slice of the source text. For a reference like `$modSHIFT` this only covers
+    /// the `$mod` part.
+    pub slice: &'a str,
+    pub is_definition: bool,
+}
+
+/// Every definition of and reference to a variable that is defined somewhere in `statements`.
+pub fn occurrences<'a>(statements: &[Statement<'a>]) -> Vec<Occurrence<'a>> {
+    let defined = statements
+        .iter()
+        .filter_map(|statement| statement.line.as_sectionable())
+        .filter_map(|info| info.lhs.strip_prefix('$'))
+        .collect::<HashSet<_>>();
+
+    let mut found = vec![];
+
+    for statement in statements {
+        let Some(info) = statement.line.as_sectionable() else {
+            continue;
+        };
+
+        if let Some(name) = info.lhs.strip_prefix('$') {
+            found.push(Occurrence {
+                source: statement.source,
+                name,
+                slice: info.lhs,
+                is_definition: true,
+            });
+        }
+
+        for reference in references(info.rhs.unwrap_or_default()) {
+            let Some(len) = longest_match(&reference[1..], |name| defined.contains(name)) else {
+                continue;
+            };
+
+            found.push(Occurrence {
+                source: statement.source,
+                name: &reference[1..=len],
+                slice: &reference[..=len],
+                is_definition: false,
+            });
+        }
+    }
+
+    found
+}
@@ -0,0 +1,186 @@
+//! Checks that formatting kept the meaning of a config, to catch formatter bugs before they cost
+//! anyone their config.
+
+use std::fmt::{self, Write};
+use std::io;
+
+use crate::config::Config;
+use crate::dump::{Setting, Settings, settings};
+use crate::parsed::bind::{bind_flags, mod_set};
+use crate::parsed::format::{FormatError, format_file};
+use crate::schema::Dialect;
+use crate::semdiff::{normalize_bind, semdiff_with};
+use crate::sources::{Source, Sources};
+
+/// How many lines of the input a bug report shows.
+const EXCERPT_LINES: usize = 40;
+
+#[derive(Debug)]
+pub enum Failure {
+    /// The formatted output can't be formatted again.
+    Reformat(FormatError),
+    /// Formatting the output again changes it, with the output formatted again.
+    NotIdempotent(String),
+    /// The output resolves to other settings than the input, with how they differ.
+    Changed(Vec<String>),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Reformat(e) => write!(f, "the output can't be formatted again: {e}"),
+            Self::NotIdempotent(_) => f.write_str("formatting the output again changes it"),
+            Self::Changed(changes) if changes.is_empty() => {
+                f.write_str("the output gives values in another order than the input")
+            }
+            Self::Changed(changes) => {
+                f.write_str("the output resolves to other settings than the input:")?;
+
+                for change in changes {
+                    write!(f, "\n  {change}")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// `value` normalized like [`semdiff`](crate::semdiff::semdiff) compares it, except that the mods
+/// of a bind are compared as they are written, unknown ones included, so losing one is caught.
+fn normalize(key: &str, value: &str) -> String {
+    if bind_flags(key).is_none() {
+        return value.to_string();
+    }
+
+    normalize_bind(value, |mods| {
+        mod_set(mods).into_iter().collect::<Vec<_>>().join(" ")
+    })
+}
+
+/// The settings `text` resolves to, normalized like [`normalize`]. Sourced files are not
+/// followed, as they are the same for the input and the output.
+fn resolve(text: &str) -> Settings {
+    let sources = Sources::load_with(Source::new(None, text.to_string()), |_| {
+        Err(io::Error::other("sourced files are not followed"))
+    });
+    let (statements, _) = sources.statements();
+
+    settings(Dialect::default().schema(), &statements)
+        .into_iter()
+        .map(|(key, setting)| {
+            let setting = match setting {
+                Setting::Value(value) => Setting::Value(normalize(&key, &value)),
+                Setting::List(values) => {
+                    Setting::List(values.iter().map(|value| normalize(&key, value)).collect())
+                }
+            };
+
+            (key, setting)
+        })
+        .collect()
+}
+
+/// Check that `formatted`, the result of formatting `input` with `config`, formats to itself and
+/// resolves to the same settings as `input`.
+pub fn verify(input: &str, formatted: &str, config: Config) -> Result<(), Failure> {
+    let reformatted = format_file(formatted, config).map_err(Failure::Reformat)?;
+    if reformatted != formatted {
+        return Err(Failure::NotIdempotent(reformatted));
+    }
+
+    let before = resolve(input);
+    let after = resolve(formatted);
+    if before != after {
+        let changes = semdiff_with(&before, &after, normalize)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        return Err(Failure::Changed(changes));
+    }
+
+    Ok(())
+}
+
+/// A report of `failure` to file as a bug: what went wrong, an excerpt of the input and both
+/// outputs.
+pub fn bug_report(input: &str, formatted: &str, failure: &Failure) -> String {
+    let mut report = String::new();
+
+    let _ = writeln!(report, "error: formatting check failed: {failure}");
+    let _ = writeln!(
+        report,
+        "This is a bug in {} {}, please report it along with what follows.",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+    );
+
+    let lines = input.lines().count();
+    if lines > EXCERPT_LINES {
+        let _ = writeln!(
+            report,
+            "\n--- input (first {EXCERPT_LINES} of {lines} lines) ---"
+        );
+    } else {
+        let _ = writeln!(report, "\n--- input ---");
+    }
+    for line in input.lines().take(EXCERPT_LINES) {
+        let _ = writeln!(report, "{line}");
+    }
+
+    let _ = writeln!(report, "\n--- output ---\n{formatted}");
+
+    if let Failure::NotIdempotent(reformatted) = failure {
+        let _ = writeln!(report, "--- output formatted again ---\n{reformatted}");
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ModsSeparator;
+
+    #[test]
+    fn test_verify() {
+        let input =
+            "$gap = 5\ngeneral {\n  gaps_in = {{ $gap*2 }}\n}\nbind = SUPER, Q, exec, kitty\n";
+        let config = Config::default();
+        let formatted = format_file(input, config).unwrap();
+
+        assert!(verify(input, &formatted, config).is_ok());
+
+        let input = "bind = shift super ,Q,exec, kitty\n";
+        let config = Config {
+            normalize_bind_mods: true,
+            ..config
+        };
+        let formatted = format_file(input, config).unwrap();
+        assert!(verify(input, &formatted, config).is_ok());
+
+        let other = formatted.replace("exec, kitty", "exec, foot");
+        let failure = verify(input, &other, config).unwrap_err();
+        assert!(matches!(&failure, Failure::Changed(changes) if changes.len() == 2));
+
+        let input = "bind = $mainMod control win FOO, Q, exec, kitty\n";
+        let config = Config {
+            bind_mods_separator: ModsSeparator::Underscore,
+            ..config
+        };
+        let formatted = format_file(input, config).unwrap();
+        assert!(formatted.contains("$mainMod SUPER_CTRL_FOO,"));
+        assert!(verify(input, &formatted, config).is_ok());
+
+        let other = formatted.replace("_FOO", "");
+        let failure = verify(input, &other, config).unwrap_err();
+        assert!(matches!(&failure, Failure::Changed(changes) if changes.len() == 2));
+
+        let failure = verify(input, &format!("{formatted}\n\n"), config).unwrap_err();
+        assert!(matches!(failure, Failure::NotIdempotent(_)));
+        let report = bug_report(input, &formatted, &failure);
+        assert!(report.contains("\n--- input ---\n"));
+        assert!(report.contains("--- output formatted again ---"));
+    }
+}
@@ -0,0 +1,169 @@
+//! Snapshot tests driven by the files in `tests/fixtures`.
+//!
+//! Every `*.conf` fixture is formatted by the `hyprlang-fmt` binary once for each option set it
+//! declares in its leading comments, with lines like:
+//!
+//! ```text
+//! # snapshot: tabs --indent-mode tabs
+//! ```
+//!
+//! that give the name of the set, followed by the arguments to run the binary with. A fixture that
+//! declares none is formatted once without arguments, as the `default` set. The output must be
+//! exactly the same as the `<fixture>.<set>.snap` file next to it. When the binary fails or prints
+//! to stderr, the snapshot ends with its exit status and what it printed there.
+//!
+//! Run with `UPDATE_SNAPSHOTS=1` to write the snapshots instead of comparing them.
+
+#![warn(clippy::cargo)]
+#![warn(clippy::complexity)]
+#![warn(clippy::correctness)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::perf)]
+#![warn(clippy::style)]
+#![warn(clippy::suspicious)]
+
+use std::collections::HashSet;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+const DIRECTIVE: &str = "# snapshot:";
+const UPDATE: &str = "UPDATE_SNAPSHOTS";
+
+/// The option sets declared by `fixture`, by name.
+fn option_sets(fixture: &str) -> Vec<(&str, Vec<&str>)> {
+    let sets = fixture
+        .lines()
+        .take_while(|line| line.starts_with('#'))
+        .filter_map(|line| line.strip_prefix(DIRECTIVE))
+        .filter_map(|set| {
+            let mut words = set.split_whitespace();
+
+            Some((words.next()?, words.collect()))
+        })
+        .collect::<Vec<_>>();
+
+    if sets.is_empty() {
+        vec![("default", vec![])]
+    } else {
+        sets
+    }
+}
+
+/// What the binary prints for `input` when run with `args`, followed by its exit status and
+/// stderr when it fails or prints to stderr.
+fn format(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_hyprlang-fmt"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("hyprlang-fmt runs");
+
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(input.as_bytes())
+        .expect("hyprlang-fmt reads stdin");
+
+    let output = child.wait_with_output().expect("hyprlang-fmt exits");
+
+    let mut snapshot = String::from_utf8(output.stdout).expect("hyprlang-fmt prints UTF-8");
+    let stderr = String::from_utf8(output.stderr).expect("hyprlang-fmt prints UTF-8");
+
+    if !output.status.success() || !stderr.is_empty() {
+        let _ = write!(snapshot, "--- {} ---\n{stderr}", output.status);
+    }
+
+    snapshot
+}
+
+/// The first line where `actual` differs from `expected`.
+fn difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.split_inclusive('\n');
+    let mut actual_lines = actual.split_inclusive('\n');
+
+    for line in 1.. {
+        let (expected, actual) = (expected_lines.next(), actual_lines.next());
+
+        if expected != actual {
+            return format!("line {line}\n  expected: {expected:?}\n  actual:   {actual:?}");
+        }
+        if expected.is_none() {
+            break;
+        }
+    }
+
+    "no difference".to_string()
+}
+
+fn files_with_extension(dir: &Path, extension: &str) -> Vec<PathBuf> {
+    let mut files = fs::read_dir(dir)
+        .expect("the fixtures directory exists")
+        .map(|entry| entry.expect("fixtures can be listed").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == extension))
+        .collect::<Vec<_>>();
+
+    files.sort();
+
+    files
+}
+
+#[test]
+fn test_snapshots() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let update = env::var_os(UPDATE).is_some();
+
+    let mut snapshots = HashSet::new();
+    let mut failures = vec![];
+
+    for fixture in files_with_extension(&dir, "conf") {
+        let input = fs::read_to_string(&fixture).expect("fixtures can be read");
+
+        for (name, args) in option_sets(&input) {
+            let snapshot = fixture.with_extension(format!("{name}.snap"));
+            let actual = format(&args, &input);
+
+            if update {
+                fs::write(&snapshot, &actual).expect("snapshots can be written");
+            } else {
+                match fs::read_to_string(&snapshot) {
+                    Ok(expected) if expected == actual => {}
+                    Ok(expected) => failures.push(format!(
+                        "{}: {}",
+                        snapshot.display(),
+                        difference(&expected, &actual)
+                    )),
+                    Err(e) => failures.push(format!("{}: {e}", snapshot.display())),
+                }
+            }
+
+            snapshots.insert(snapshot);
+        }
+    }
+
+    // Snapshots of option sets that are no longer declared
+    for stale in files_with_extension(&dir, "snap") {
+        if snapshots.contains(&stale) {
+            continue;
+        }
+
+        if update {
+            fs::remove_file(&stale).expect("stale snapshots can be removed");
+        } else {
+            failures.push(format!("{}: not declared by any fixture", stale.display()));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} snapshot(s) failed, run with {UPDATE}=1 to update them:\n\n{}",
+        failures.len(),
+        failures.join("\n\n")
+    );
+}